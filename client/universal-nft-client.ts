@@ -170,12 +170,12 @@ export class UniversalNftClient {
   async receiveCrossChain(
     recipient: PublicKey,
     tokenId: Buffer,
+    originChainId: number,
     name: string,
     symbol: string,
     uri: string,
     creators?: any[]
   ): Promise<{ signature: string; mint: PublicKey }> {
-    const mint = Keypair.generate();
     const [programStatePda] = PublicKey.findProgramAddressSync(
       [Buffer.from("program_state")],
       this.program.programId
@@ -186,13 +186,19 @@ export class UniversalNftClient {
       this.program.programId
     );
 
+    // The mint of a token ID is a PDA, reused when the NFT returns to Solana
+    const [mint] = PublicKey.findProgramAddressSync(
+      [Buffer.from("mint"), tokenId],
+      this.program.programId
+    );
+
     const tokenAccount = await getAssociatedTokenAddress(
-      mint.publicKey,
+      mint,
       recipient
     );
 
     const [nftOrigin] = PublicKey.findProgramAddressSync(
-      [Buffer.from("nft_origin"), tokenId],
+      [Buffer.from("nft_origin"), mint.toBuffer()],
       this.program.programId
     );
 
@@ -200,16 +206,16 @@ export class UniversalNftClient {
       [
         Buffer.from("metadata"),
         new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s").toBuffer(),
-        mint.publicKey.toBuffer(),
+        mint.toBuffer(),
       ],
       new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s")
     );
 
     const signature = await this.program.methods
-      .receiveCrossChain(Array.from(tokenId), name, symbol, uri, creators || null)
+      .receiveCrossChain(Array.from(tokenId), new anchor.BN(originChainId), name, symbol, uri, creators || null)
       .accounts({
         programState: programStatePda,
-        mint: mint.publicKey,
+        mint,
         tokenAccount,
         nftOrigin,
         metadata,
@@ -223,28 +229,49 @@ export class UniversalNftClient {
         rent: anchor.web3.SYSVAR_RENT_PUBKEY,
         tokenMetadataProgram: new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"),
       })
-      .signers([mint])
       .rpc();
 
     console.log("NFT received from cross-chain:", signature);
-    return { signature, mint: mint.publicKey };
+    return { signature, mint };
+  }
+
+  /**
+   * Queue a gateway change, applied by executeGatewayUpdate once the
+   * update delay has elapsed (admin only)
+   */
+  async queueGatewayUpdate(authority: Keypair, newGateway: PublicKey): Promise<string> {
+    const [programStatePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("program_state")],
+      this.program.programId
+    );
+
+    const signature = await this.program.methods
+      .queueGatewayUpdate(newGateway)
+      .accounts({
+        programState: programStatePda,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc();
+
+    console.log("Gateway update queued:", signature);
+    return signature;
   }
 
   /**
-   * Update gateway address (admin only)
+   * Apply the queued gateway change once its delay has elapsed (admin only)
    */
-  async updateGateway(authority: Keypair, newGateway: PublicKey): Promise<string> {
+  async executeGatewayUpdate(authority: Keypair): Promise<string> {
     const [programStatePda] = PublicKey.findProgramAddressSync(
       [Buffer.from("program_state")],
       this.program.programId
     );
 
     const signature = await this.program.methods
-      .updateGateway(newGateway)
+      .executeGatewayUpdate()
       .accounts({
         programState: programStatePda,
         authority: authority.publicKey,
-        newGateway,
       })
       .signers([authority])
       .rpc();
@@ -253,6 +280,28 @@ export class UniversalNftClient {
     return signature;
   }
 
+  /**
+   * Drop the queued gateway change (admin or pauser)
+   */
+  async cancelGatewayUpdate(signer: Keypair): Promise<string> {
+    const [programStatePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("program_state")],
+      this.program.programId
+    );
+
+    const signature = await this.program.methods
+      .cancelGatewayUpdate()
+      .accounts({
+        programState: programStatePda,
+        signer: signer.publicKey,
+      })
+      .signers([signer])
+      .rpc();
+
+    console.log("Gateway update cancelled:", signature);
+    return signature;
+  }
+
   /**
    * Get program state
    */
@@ -663,7 +663,9 @@ export class UniversalNFTService {
       'receive_cross_chain': Buffer.from([117, 33, 7, 40, 221, 135, 87, 40]),
       'on_call': Buffer.from([16, 136, 66, 32, 254, 40, 181, 8]),
       'on_revert': Buffer.from([226, 44, 101, 52, 224, 214, 41, 9]),
-      'update_gateway': Buffer.from([11, 133, 146, 0, 122, 116, 10, 75]),
+      'queue_gateway_update': Buffer.from([252, 250, 226, 193, 100, 109, 116, 123]),
+      'execute_gateway_update': Buffer.from([222, 180, 83, 167, 177, 43, 201, 197]),
+      'cancel_gateway_update': Buffer.from([122, 99, 91, 205, 78, 169, 96, 75]),
     };
 
     return discriminators[instruction] || Buffer.alloc(8);
@@ -0,0 +1,212 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Size of an ABI word
+pub const WORD_LEN: usize = 32;
+
+/// Number of head words: receiver, tokenId, uri offset, sender,
+/// destinationChainId, originChainId, name offset, symbol offset
+const HEAD_WORDS: usize = 8;
+
+/// Universal NFT payload in the ZetaChain Universal NFT standard layout:
+/// `abi.encode(address receiver, uint256 tokenId, string uri, address sender,
+/// uint256 destinationChainId, uint256 originChainId, string name, string symbol)`.
+///
+/// `receiver` holds the full 32-byte head word. For EVM destinations it is a
+/// left-padded address; for Solana it is the recipient's public key, which the
+/// Solidity side reads as `bytes32`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UniversalNftPayload {
+    pub receiver: [u8; 32],
+    pub token_id: [u8; 32],
+    pub uri: String,
+    pub sender: [u8; 20],
+    pub destination_chain_id: u64,
+    /// Chain the NFT was first minted on
+    pub origin_chain_id: u64,
+    pub name: String,
+    pub symbol: String,
+}
+
+impl UniversalNftPayload {
+    pub fn from_message(message: &CrossChainMessage) -> Self {
+        UniversalNftPayload {
+            receiver: message.recipient,
+            token_id: message.token_id,
+            uri: message.metadata.uri.clone(),
+            sender: message.sender,
+            destination_chain_id: message.destination_chain_id,
+            origin_chain_id: message.origin_chain_id,
+            name: message.metadata.name.clone(),
+            symbol: message.metadata.symbol.clone(),
+        }
+    }
+
+    /// Expands the payload into a transfer message. The ABI layout does not
+    /// carry the source chain, so it comes from the chain configuration the
+    /// payload was received under.
+    pub fn into_message(self, source_chain_id: u64, timestamp: i64) -> CrossChainMessage {
+        CrossChainMessage {
+            message_type: CrossChainMessageType::Transfer,
+            token_id: self.token_id,
+            source_chain_id,
+            destination_chain_id: self.destination_chain_id,
+            sender: self.sender,
+            recipient: self.receiver,
+            metadata: NftMetadata {
+                name: self.name,
+                symbol: self.symbol,
+                uri: self.uri,
+                seller_fee_basis_points: 0,
+                creators: None,
+                collection: None,
+            },
+            timestamp,
+            origin_chain_id: self.origin_chain_id,
+        }
+    }
+}
+
+/// Encodes the payload with the Solidity ABI rules for `abi.encode`
+pub fn encode_universal_nft_payload(payload: &UniversalNftPayload) -> Vec<u8> {
+    let strings = [&payload.uri, &payload.name, &payload.symbol];
+    let mut offsets = [0u64; 3];
+    let mut offset = HEAD_WORDS * WORD_LEN;
+    for (string, string_offset) in strings.iter().zip(offsets.iter_mut()) {
+        *string_offset = offset as u64;
+        offset += WORD_LEN + string.len().div_ceil(WORD_LEN) * WORD_LEN;
+    }
+
+    let mut data = Vec::with_capacity(offset);
+    data.extend_from_slice(&payload.receiver);
+    data.extend_from_slice(&payload.token_id);
+    data.extend_from_slice(&uint_word(offsets[0]));
+    data.extend_from_slice(&address_word(&payload.sender));
+    data.extend_from_slice(&uint_word(payload.destination_chain_id));
+    data.extend_from_slice(&uint_word(payload.origin_chain_id));
+    data.extend_from_slice(&uint_word(offsets[1]));
+    data.extend_from_slice(&uint_word(offsets[2]));
+    for string in strings {
+        let bytes = string.as_bytes();
+        data.extend_from_slice(&uint_word(bytes.len() as u64));
+        data.extend_from_slice(bytes);
+        data.resize(data.len().div_ceil(WORD_LEN) * WORD_LEN, 0);
+    }
+    data
+}
+
+/// Decodes an ABI encoded Universal NFT payload.
+///
+/// Only the canonical encoding produced by `abi.encode` is accepted: the
+/// strings must directly follow the head in order, padding must be zero and
+/// no bytes may trail.
+pub fn decode_universal_nft_payload(data: &[u8]) -> Result<UniversalNftPayload> {
+    require!(
+        data.len() >= HEAD_WORDS * WORD_LEN,
+        UniversalNftError::MessageTruncated
+    );
+
+    let receiver: [u8; 32] = word(data, 0).try_into().unwrap();
+    let token_id: [u8; 32] = word(data, 1).try_into().unwrap();
+    let sender = read_address(word(data, 3))?;
+    let destination_chain_id = read_uint(word(data, 4))?;
+    let origin_chain_id = read_uint(word(data, 5))?;
+
+    let (uri, name_offset) = read_string(data, 2, HEAD_WORDS * WORD_LEN)?;
+    let (name, symbol_offset) = read_string(data, 6, name_offset)?;
+    let (symbol, end) = read_string(data, 7, symbol_offset)?;
+    require!(end == data.len(), UniversalNftError::InvalidAbiEncoding);
+
+    require!(receiver != [0u8; 32], UniversalNftError::InvalidRecipient);
+    require!(token_id != [0u8; 32], UniversalNftError::ZeroTokenId);
+    require!(origin_chain_id != 0, UniversalNftError::InvalidChainId);
+
+    Ok(UniversalNftPayload {
+        receiver,
+        token_id,
+        uri,
+        sender,
+        destination_chain_id,
+        origin_chain_id,
+        name,
+        symbol,
+    })
+}
+
+/// Decodes a payload delivered to this chain, additionally checking that it
+/// was addressed to `local_chain_id`
+pub fn decode_inbound_payload(data: &[u8], local_chain_id: u64) -> Result<UniversalNftPayload> {
+    let payload = decode_universal_nft_payload(data)?;
+
+    require!(
+        payload.destination_chain_id == local_chain_id,
+        UniversalNftError::WrongDestinationChain
+    );
+
+    Ok(payload)
+}
+
+/// Left-pads an EVM address to an ABI word
+pub fn address_word(address: &[u8; 20]) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[12..].copy_from_slice(address);
+    word
+}
+
+fn uint_word(value: u64) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+fn word(data: &[u8], index: usize) -> &[u8] {
+    &data[index * WORD_LEN..(index + 1) * WORD_LEN]
+}
+
+/// Reads a length or offset word, which must fit in a u64
+fn read_uint(word: &[u8]) -> Result<u64> {
+    require!(
+        word[..24].iter().all(|b| *b == 0),
+        UniversalNftError::InvalidAbiEncoding
+    );
+    Ok(u64::from_be_bytes(word[24..].try_into().unwrap()))
+}
+
+fn read_address(word: &[u8]) -> Result<[u8; 20]> {
+    require!(
+        word[..12].iter().all(|b| *b == 0),
+        UniversalNftError::InvalidAbiEncoding
+    );
+    Ok(word[12..].try_into().unwrap())
+}
+
+/// Reads the string whose offset is in head word `index`, which must point at
+/// `expected_offset`. Returns the string and the offset just past its tail.
+fn read_string(data: &[u8], index: usize, expected_offset: usize) -> Result<(String, usize)> {
+    require!(
+        read_uint(word(data, index))? == expected_offset as u64,
+        UniversalNftError::InvalidAbiEncoding
+    );
+    require!(
+        data.len() >= expected_offset + WORD_LEN,
+        UniversalNftError::MessageTruncated
+    );
+
+    let len = read_uint(&data[expected_offset..expected_offset + WORD_LEN])? as usize;
+    let start = expected_offset + WORD_LEN;
+    require!(len <= data.len() - start, UniversalNftError::MessageTruncated);
+    let padded_len = len.div_ceil(WORD_LEN) * WORD_LEN;
+    require!(
+        data.len() - start >= padded_len,
+        UniversalNftError::MessageTruncated
+    );
+    require!(
+        data[start + len..start + padded_len].iter().all(|b| *b == 0),
+        UniversalNftError::InvalidAbiEncoding
+    );
+
+    let string = String::from_utf8(data[start..start + len].to_vec())
+        .map_err(|_| UniversalNftError::InvalidDataFormat)?;
+    Ok((string, start + padded_len))
+}
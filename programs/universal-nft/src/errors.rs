@@ -53,7 +53,7 @@ pub enum UniversalNftError {
     #[msg("Invalid data format - could not parse UTF-8")]
     InvalidDataFormat,
     
-    #[msg("Revert message detected - transaction execution halted")]
+    #[msg("Revert message detected - transaction execution halted (test-hooks builds only)")]
     RevertMessage,
     
     #[msg("Gateway deposit failed")]
@@ -67,4 +67,175 @@ pub enum UniversalNftError {
     
     #[msg("Program state not initialized")]
     ProgramNotInitialized,
-}
\ No newline at end of file
+    
+    #[msg("Transfer record does not match the reverted token")]
+    TransferRecordMismatch,
+    
+    #[msg("Transfer is not in progress and cannot be reverted")]
+    TransferNotInProgress,
+    
+    #[msg("Revert address does not match the transfer record")]
+    InvalidRevertAddress,
+    
+    #[msg("Insufficient lamports available for refund")]
+    InsufficientRefundBalance,
+    
+    #[msg("Insufficient lamports deposited with the inbound call")]
+    InsufficientInboundFunds,
+    
+    #[msg("Cross-chain message is truncated")]
+    MessageTruncated,
+    
+    #[msg("Unknown cross-chain message type")]
+    UnknownMessageType,
+    
+    #[msg("Cross-chain message is addressed to a different chain")]
+    WrongDestinationChain,
+    
+    #[msg("Cross-chain message carries a zero token ID")]
+    ZeroTokenId,
+    
+    #[msg("Cross-chain message envelope has an invalid magic")]
+    InvalidMessageMagic,
+    
+    #[msg("Unsupported cross-chain message version")]
+    UnsupportedMessageVersion,
+    
+    #[msg("Message is not a canonical ABI encoding of the Universal NFT payload")]
+    InvalidAbiEncoding,
+    
+    #[msg("Mint account is not the PDA of the token ID")]
+    InvalidMintAddress,
+    
+    #[msg("Expected a secp256k1 signature instruction right before this instruction")]
+    InvalidSignatureInstruction,
+    
+    #[msg("Signature does not cover the expected address and message")]
+    SignatureMismatch,
+    
+    #[msg("EVM address must not be zero")]
+    InvalidEvmAddress,
+    
+    #[msg("Link an EVM address to the wallet before transferring cross-chain")]
+    EvmAddressLinkRequired,
+    
+    #[msg("On-revert gas limit is outside the bounds configured for the destination chain")]
+    RevertGasLimitOutOfBounds,
+    
+    #[msg("Gateway PDA is not the meta PDA of the configured gateway")]
+    InvalidGatewayPda,
+    
+    #[msg("No TSS address is configured for signed execution")]
+    TssAddressNotSet,
+    
+    #[msg("Signed execution nonce does not match the expected nonce")]
+    InvalidNonce,
+    
+    #[msg("Operation is paused")]
+    ProgramPaused,
+    
+    #[msg("Pause flags must be a non-empty combination of the known pause bits")]
+    InvalidPauseFlags,
+    
+    #[msg("A gateway or update delay change is already queued")]
+    GatewayUpdateAlreadyQueued,
+    
+    #[msg("No gateway or update delay change is queued")]
+    NoGatewayUpdateQueued,
+    
+    #[msg("Queued gateway update delay has not elapsed")]
+    GatewayUpdateNotReady,
+    
+    #[msg("Gateway update delay is below the minimum")]
+    GatewayUpdateDelayTooShort,
+    
+    #[msg("Program state is already at the current version")]
+    StateAlreadyMigrated,
+    
+    #[msg("Program state version cannot be migrated")]
+    UnsupportedStateVersion,
+    
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+    
+    #[msg("Fee basis points exceed 10000")]
+    InvalidFeeBps,
+    
+    #[msg("Treasury balance would drop below its rent-exempt minimum")]
+    InsufficientTreasuryBalance,
+    
+    #[msg("Proceeds shares must name payees and add up to 10000 basis points")]
+    InvalidProceedsSplit,
+    
+    #[msg("Minting has not started")]
+    MintNotStarted,
+    
+    #[msg("Minting has ended")]
+    MintEnded,
+    
+    #[msg("Maximum supply reached")]
+    MaxSupplyReached,
+    
+    #[msg("Wallet mint limit reached")]
+    WalletMintLimitReached,
+    
+    #[msg("Payment accounts do not match the mint settings")]
+    InvalidPaymentAccount,
+    
+    #[msg("Minting requires an allowlist proof and claims page")]
+    AllowlistProofRequired,
+    
+    #[msg("Allowlist proof does not match the claimant")]
+    InvalidAllowlistProof,
+    
+    #[msg("Allowlist ticket has already been claimed")]
+    TicketAlreadyClaimed,
+    
+    #[msg("Claims page does not cover this ticket of the current allowlist")]
+    AllowlistPageMismatch,
+    
+    #[msg("Inbound allowlist claims are only accepted for free mints")]
+    InboundClaimNotFree,
+    
+    #[msg("Batch is empty or its accounts do not match its items")]
+    InvalidBatch,
+    
+    #[msg("NFT is already on Solana")]
+    NftAlreadyOnSolana,
+    
+    #[msg("NFT is not frozen with the mint authority as delegate in the given token account")]
+    NftNotLocked,
+    
+    #[msg("Revert context metadata hash does not match the transferred NFT")]
+    MetadataHashMismatch,
+    
+    #[msg("Chain config is already at the current layout")]
+    ChainConfigAlreadyMigrated,
+    
+    #[msg("Mints priced in an SPL token must split their proceeds between payees")]
+    ProceedsSplitRequired,
+    
+    #[msg("Allowlist claims are only accepted from the configured universal contract")]
+    UntrustedClaimSender,
+    
+    #[msg("Only aborted transfers can be recovered")]
+    TransferNotAborted,
+    
+    #[msg("A TSS address rotation is already queued")]
+    TssUpdateAlreadyQueued,
+    
+    #[msg("No TSS address rotation is queued")]
+    NoTssUpdateQueued,
+    
+    #[msg("Queued TSS address rotation delay has not elapsed")]
+    TssUpdateNotReady,
+    
+    #[msg("A pauser change is already queued")]
+    PauserUpdateAlreadyQueued,
+    
+    #[msg("No pauser change is queued")]
+    NoPauserUpdateQueued,
+    
+    #[msg("Queued pauser change delay has not elapsed")]
+    PauserUpdateNotReady,
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::sysvar::instructions::get_instruction_relative;
+use crate::state::*;
+use crate::errors::*;
+
+/// ZetaChain gateway instruction discriminator for deposit_and_call
+pub const DEPOSIT_AND_CALL_DISCRIMINATOR: [u8; 8] = [0x66, 0x87, 0x6a, 0x4d, 0x1c, 0x9a, 0x8b, 0x13];
+
+/// Seed of the gateway's `meta` PDA, which holds its state and signs its calls
+pub const GATEWAY_PDA_SEED: &[u8] = b"meta";
+
+/// Address of the gateway PDA of `gateway_program`
+pub fn gateway_pda_address(gateway_program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[GATEWAY_PDA_SEED], gateway_program).0
+}
+
+/// Arguments of the gateway `deposit_and_call` instruction, in wire order
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct DepositAndCallArgs {
+    /// Lamports deposited alongside the call
+    pub amount: u64,
+    /// Receiver on the destination chain
+    pub receiver: [u8; 32],
+    /// Encoded cross-chain message
+    pub message: Vec<u8>,
+    /// Revert handling for the call
+    pub revert_options: Option<RevertOptions>,
+}
+
+/// Serializes `deposit_and_call` instruction data
+pub fn encode_deposit_and_call_data(args: &DepositAndCallArgs) -> Result<Vec<u8>> {
+    let mut data = DEPOSIT_AND_CALL_DISCRIMINATOR.to_vec();
+    args.serialize(&mut data)?;
+    Ok(data)
+}
+
+/// Parses `deposit_and_call` instruction data, rejecting other instructions
+/// and trailing bytes
+pub fn decode_deposit_and_call_data(data: &[u8]) -> Result<DepositAndCallArgs> {
+    require!(
+        data.len() >= DEPOSIT_AND_CALL_DISCRIMINATOR.len()
+            && data[..DEPOSIT_AND_CALL_DISCRIMINATOR.len()] == DEPOSIT_AND_CALL_DISCRIMINATOR,
+        UniversalNftError::InvalidInstructionData
+    );
+    
+    DepositAndCallArgs::try_from_slice(&data[DEPOSIT_AND_CALL_DISCRIMINATOR.len()..])
+        .map_err(|_| UniversalNftError::InvalidInstructionData.into())
+}
+
+/// Builds the gateway `deposit_and_call` instruction
+pub fn deposit_and_call_instruction(
+    gateway_program: &Pubkey,
+    gateway_pda: &Pubkey,
+    payer: &Pubkey,
+    args: &DepositAndCallArgs,
+) -> Result<Instruction> {
+    Ok(Instruction {
+        program_id: *gateway_program,
+        accounts: vec![
+            AccountMeta::new(*gateway_pda, false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(anchor_lang::solana_program::system_program::id(), false),
+        ],
+        data: encode_deposit_and_call_data(args)?,
+    })
+}
+
+/// Verifies that the running instruction is a call from the trusted gateway.
+///
+/// Every gateway-only instruction runs this check. A program cannot sign as
+/// a wallet, so the gateway proves itself twice instead:
+/// - its PDA, owned by the gateway program, signs the CPI, which only the
+///   gateway can do through `invoke_signed`;
+/// - the top-level instruction, read through instruction introspection,
+///   belongs to the gateway program, so the call did not come from elsewhere.
+pub fn verify_gateway_caller(
+    gateway: &Pubkey,
+    gateway_pda: &AccountInfo,
+    instructions_sysvar: &AccountInfo,
+) -> Result<()> {
+    require!(gateway_pda.is_signer, UniversalNftError::InvalidGatewayCaller);
+    require_keys_eq!(*gateway_pda.owner, *gateway, UniversalNftError::InvalidGatewayCaller);
+    
+    let current_ix = get_instruction_relative(0, instructions_sysvar)?;
+    msg!("Caller program: {}", current_ix.program_id);
+    msg!("Expected gateway: {}", gateway);
+    require_keys_eq!(current_ix.program_id, *gateway, UniversalNftError::InvalidGatewayCaller);
+    
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNftError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = program_state.pending_authority == new_authority.key() @ UniversalNftError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Proposed authority, signing to prove it controls the key
+    pub new_authority: Signer<'info>,
+}
+
+/// First step of an authority transfer. Proposing `Pubkey::default()`
+/// cancels a pending proposal.
+pub fn propose_authority(
+    ctx: Context<ProposeAuthority>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+    
+    program_state.pending_authority = new_authority;
+    
+    msg!("Authority proposed");
+    msg!("Current authority: {}", program_state.authority);
+    msg!("Proposed authority: {}", new_authority);
+    
+    emit!(AuthorityProposedEvent {
+        authority: program_state.authority,
+        proposed_authority: new_authority,
+    });
+    
+    Ok(())
+}
+
+/// Second step of an authority transfer, completed by the proposed key
+pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+    
+    let old_authority = program_state.authority;
+    program_state.authority = ctx.accounts.new_authority.key();
+    program_state.pending_authority = Pubkey::default();
+    
+    msg!("Authority transferred");
+    msg!("Old authority: {}", old_authority);
+    msg!("New authority: {}", program_state.authority);
+    
+    emit!(AuthorityTransferredEvent {
+        old_authority,
+        new_authority: program_state.authority,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct AuthorityProposedEvent {
+    pub authority: Pubkey,
+    pub proposed_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTransferredEvent {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
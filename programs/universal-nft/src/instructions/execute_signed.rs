@@ -0,0 +1,209 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::keccak;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::Token;
+use crate::state::*;
+use crate::errors::*;
+use crate::nft::{issue_inbound_nft, InboundNftAccounts};
+use crate::secp256k1::{load_preceding_instruction, verify_secp256k1_instruction};
+use super::on_call::decode_message;
+
+/// Inbound message authorized by a TSS signature instead of a gateway call
+#[derive(Accounts)]
+pub struct ExecuteSigned<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    /// Settings of ZetaChain, on whose behalf the TSS signs, selecting the
+    /// message codec
+    #[account(
+        seeds = [b"chain_config", ZETACHAIN_CHAIN_ID.to_le_bytes().as_ref()],
+        bump = chain_config.bump,
+        constraint = chain_config.enabled @ UniversalNftError::CrossChainNotSupported
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+
+    /// Relayer submitting the signed message and paying for new accounts
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: NFT recipient, validated against the message recipient
+    pub recipient: UncheckedAccount<'info>,
+
+    /// CHECK: Mint PDA of the message token ID, created on first arrival
+    #[account(mut)]
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: Recipient's associated token account, created if missing
+    #[account(mut)]
+    pub token_account: UncheckedAccount<'info>,
+
+    /// CHECK: Origin record PDA of the mint, created on first arrival
+    #[account(mut)]
+    pub nft_origin: UncheckedAccount<'info>,
+
+    /// CHECK: Metadata account will be created by Metaplex
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Mint authority PDA, delegate of the frozen Solana-native NFTs
+    #[account(
+        mut,
+        seeds = [b"mint_authority"],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Master edition of a returning Solana-native NFT, checked by `nft::thaw_nft`
+    pub master_edition: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Token account a returning Solana-native NFT was frozen in,
+    /// checked by `nft::thaw_nft`
+    #[account(mut)]
+    pub locked_token_account: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    /// CHECK: Metaplex token metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    /// CHECK: Instruction sysvar account holding the secp256k1 proof
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+    pub instruction_sysvar_account: UncheckedAccount<'info>,
+}
+
+/// Bytes the TSS key signs to authorize `execute_signed`:
+/// `uint64 chain_id || bytes32 program_id || uint64 nonce || keccak256(data)`,
+/// integers big-endian. The program ID keeps signatures for one deployment
+/// from being replayed against another.
+pub fn execute_signed_message(chain_id: u64, program_id: &Pubkey, nonce: u64, data: &[u8]) -> Vec<u8> {
+    let mut message = Vec::with_capacity(8 + 32 + 8 + 32);
+    message.extend_from_slice(&chain_id.to_be_bytes());
+    message.extend_from_slice(program_id.as_ref());
+    message.extend_from_slice(&nonce.to_be_bytes());
+    message.extend_from_slice(&keccak::hash(data).to_bytes());
+    message
+}
+
+/// Checks that `signature_instruction`, at `instruction_index` in the
+/// transaction, proves the current TSS key signed `data` for this program on
+/// Solana with the next expected `nonce`
+pub fn verify_tss_execution(
+    program_state: &ProgramState,
+    program_id: &Pubkey,
+    signature_instruction: &Instruction,
+    instruction_index: u16,
+    nonce: u64,
+    data: &[u8],
+) -> Result<()> {
+    require!(program_state.tss_address != [0u8; 20], UniversalNftError::TssAddressNotSet);
+    require!(nonce == program_state.tss_nonce, UniversalNftError::InvalidNonce);
+
+    verify_secp256k1_instruction(
+        signature_instruction,
+        instruction_index,
+        &program_state.tss_address,
+        &execute_signed_message(SOLANA_CHAIN_ID, program_id, nonce, data),
+    )
+}
+
+pub fn execute_signed(
+    ctx: Context<ExecuteSigned>,
+    nonce: u64,
+    data: Vec<u8>,
+) -> Result<()> {
+    msg!("=== EXECUTE SIGNED START ===");
+    msg!("Nonce: {}", nonce);
+    msg!("Data length: {} bytes", data.len());
+
+    ctx.accounts.program_state.require_not_paused(PAUSE_INBOUND)?;
+
+    // The TSS key proves the message through a secp256k1 instruction
+    let (signature_instruction, index) =
+        load_preceding_instruction(&ctx.accounts.instruction_sysvar_account.to_account_info())?;
+    verify_tss_execution(
+        &ctx.accounts.program_state,
+        ctx.program_id,
+        &signature_instruction,
+        index,
+        nonce,
+        &data,
+    )?;
+    msg!("✅ TSS signature verified");
+
+    let message = decode_message(&ctx.accounts.chain_config, &data)?;
+    msg!("Parsed message type: {:?}", message.message_type);
+    msg!("Token ID: {:?}", message.token_id);
+
+    match message.message_type {
+        CrossChainMessageType::Mint | CrossChainMessageType::Transfer => {}
+        _ => {
+            msg!("Unsupported message type: {:?}", message.message_type);
+            return Err(UniversalNftError::InvalidCrossChainMessage.into());
+        }
+    }
+
+    let accounts = &ctx.accounts;
+    let is_returning_nft = issue_inbound_nft(
+        &InboundNftAccounts {
+            program_id: ctx.program_id,
+            payer: accounts.payer.to_account_info(),
+            payer_seeds: None,
+            mint: accounts.mint.to_account_info(),
+            token_account: accounts.token_account.to_account_info(),
+            nft_origin: accounts.nft_origin.to_account_info(),
+            metadata: accounts.metadata.to_account_info(),
+            mint_authority: accounts.mint_authority.to_account_info(),
+            mint_authority_bump: ctx.bumps.mint_authority,
+            recipient: accounts.recipient.to_account_info(),
+            token_program: accounts.token_program.to_account_info(),
+            associated_token_program: accounts.associated_token_program.to_account_info(),
+            system_program: accounts.system_program.to_account_info(),
+            rent: accounts.rent.to_account_info(),
+            token_metadata_program: accounts.token_metadata_program.to_account_info(),
+            master_edition: accounts.master_edition.as_ref().map(|account| account.to_account_info()),
+            locked_token_account: accounts
+                .locked_token_account
+                .as_ref()
+                .map(|account| account.to_account_info()),
+        },
+        &message,
+    )?;
+
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.tss_nonce += 1;
+    if !is_returning_nft {
+        program_state.total_minted += 1;
+    }
+    program_state.total_receives += 1;
+
+    emit!(SignedExecutionEvent {
+        nonce,
+        token_id: message.token_id,
+        source_chain_id: message.source_chain_id,
+        payer: ctx.accounts.payer.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("✅ Signed execution completed, next nonce: {}", program_state.tss_nonce);
+    msg!("=== EXECUTE SIGNED END ===");
+
+    Ok(())
+}
+
+#[event]
+pub struct SignedExecutionEvent {
+    pub nonce: u64,
+    pub token_id: [u8; 32],
+    pub source_chain_id: u64,
+    pub payer: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+#[instruction(page: u32)]
+pub struct InitAllowlistClaims<'info> {
+    #[account(
+        seeds = [b"mint_config"],
+        bump = mint_config.bump,
+        constraint = mint_config.settings.merkle_root != [0u8; 32] @ UniversalNftError::InvalidInstructionData
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + AllowlistClaims::INIT_SPACE,
+        seeds = [
+            b"allowlist_claims",
+            mint_config.settings.merkle_root.as_ref(),
+            page.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub allowlist_claims: Box<Account<'info, AllowlistClaims>>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the claims page of the current allowlist holding tickets
+/// `page * ALLOWLIST_TICKETS_PER_PAGE` onwards. Anyone may pay for it.
+pub fn init_allowlist_claims(ctx: Context<InitAllowlistClaims>, page: u32) -> Result<()> {
+    let allowlist_claims = &mut ctx.accounts.allowlist_claims;
+    allowlist_claims.merkle_root = ctx.accounts.mint_config.settings.merkle_root;
+    allowlist_claims.page = page;
+    allowlist_claims.bump = ctx.bumps.allowlist_claims;
+    
+    msg!("Allowlist claims page created");
+    msg!("Merkle root: 0x{}", hex::encode(allowlist_claims.merkle_root));
+    msg!("Page: {}", page);
+    
+    Ok(())
+}
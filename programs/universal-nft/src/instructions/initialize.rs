@@ -7,12 +7,39 @@ pub struct Initialize<'info> {
     #[account(
         init,
         payer = authority,
-        space = ProgramState::LEN,
+        space = 8 + ProgramState::INIT_SPACE,
         seeds = [b"program_state"],
         bump
     )]
     pub program_state: Account<'info, ProgramState>,
     
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Treasury::INIT_SPACE,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Roles::INIT_SPACE,
+        seeds = [b"roles"],
+        bump
+    )]
+    pub roles: Account<'info, Roles>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + MintConfig::INIT_SPACE,
+        seeds = [b"mint_config"],
+        bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+    
     #[account(mut)]
     pub authority: Signer<'info>,
     
@@ -27,7 +54,7 @@ pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
     msg!("Program State PDA: {}", ctx.accounts.program_state.key());
     msg!("Authority: {}", ctx.accounts.authority.key());
     msg!("Gateway: {}", ctx.accounts.gateway.key());
-    msg!("Program State Space: {} bytes", ProgramState::LEN);
+    msg!("Program State Space: {} bytes", 8 + ProgramState::INIT_SPACE);
     msg!("Bump: {}", ctx.bumps.program_state);
     
     // Validate gateway address is not default
@@ -45,8 +72,42 @@ pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
     program_state.total_minted = 0;
     program_state.total_transfers = 0;
     program_state.total_receives = 0;
-    program_state.version = 1;
+    program_state.version = PROGRAM_STATE_VERSION;
     program_state.bump = ctx.bumps.program_state;
+    program_state.tss_address = [0u8; 20];
+    program_state.tss_nonce = 0;
+    program_state.pauser = Pubkey::default();
+    program_state.paused = 0;
+    program_state.pending_gateway = Pubkey::default();
+    program_state.gateway_update_eta = 0;
+    program_state.gateway_update_delay = DEFAULT_GATEWAY_UPDATE_DELAY;
+    program_state.pending_authority = Pubkey::default();
+    program_state.pending_gateway_update_delay = 0;
+    program_state.pending_tss_address = [0u8; 20];
+    program_state.tss_update_eta = 0;
+    program_state.pending_pauser = Pubkey::default();
+    program_state.pauser_update_eta = 0;
+    
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.total_collected = 0;
+    treasury.bump = ctx.bumps.treasury;
+    
+    // Every role starts unassigned, leaving it to the authority
+    let roles = &mut ctx.accounts.roles;
+    roles.set_inner(Roles {
+        fee_manager: Pubkey::default(),
+        minter: Pubkey::default(),
+        chain_manager: Pubkey::default(),
+        bump: ctx.bumps.roles,
+    });
+    
+    // Minting starts free and unlimited until configured
+    let mint_config = &mut ctx.accounts.mint_config;
+    mint_config.set_inner(MintConfig {
+        settings: MintSettings::default(),
+        minted: 0,
+        bump: ctx.bumps.mint_config,
+    });
     
     msg!("✅ Program state initialized successfully:");
     msg!("  Authority: {}", program_state.authority);
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::secp256k1::verify_preceding_secp256k1_signature;
+
+#[derive(Accounts)]
+pub struct LinkEvmAddress<'info> {
+    #[account(
+        init_if_needed,
+        payer = wallet,
+        space = 8 + AddressLink::INIT_SPACE,
+        seeds = [b"address_link", wallet.key().as_ref()],
+        bump
+    )]
+    pub address_link: Account<'info, AddressLink>,
+    
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// CHECK: Instruction sysvar account holding the secp256k1 proof
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+    pub instruction_sysvar_account: UncheckedAccount<'info>,
+}
+
+/// Message the EVM key signs to link itself to `wallet`.
+///
+/// It carries the `personal_sign` prefix, so EVM wallets can produce the
+/// signature without custom tooling.
+pub fn address_link_message(wallet: &Pubkey, program_id: &Pubkey) -> Vec<u8> {
+    let text = format!("Link Solana wallet {} to Universal NFT program {}", wallet, program_id);
+    format!("\x19Ethereum Signed Message:\n{}{}", text.len(), text).into_bytes()
+}
+
+pub fn link_evm_address(ctx: Context<LinkEvmAddress>, evm_address: [u8; 20]) -> Result<()> {
+    require!(evm_address != [0u8; 20], UniversalNftError::InvalidEvmAddress);
+    
+    // The EVM key proves ownership through a secp256k1 instruction
+    let wallet = ctx.accounts.wallet.key();
+    verify_preceding_secp256k1_signature(
+        &ctx.accounts.instruction_sysvar_account.to_account_info(),
+        &evm_address,
+        &address_link_message(&wallet, ctx.program_id),
+    )?;
+    
+    let address_link = &mut ctx.accounts.address_link;
+    address_link.wallet = wallet;
+    address_link.evm_address = evm_address;
+    address_link.linked_at = Clock::get()?.unix_timestamp;
+    address_link.bump = ctx.bumps.address_link;
+    
+    msg!("EVM address linked");
+    msg!("Wallet: {}", wallet);
+    msg!("EVM address: 0x{}", hex::encode(evm_address));
+    
+    emit!(EvmAddressLinkedEvent {
+        wallet,
+        evm_address,
+        timestamp: address_link.linked_at,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct EvmAddressLinkedEvent {
+    pub wallet: Pubkey,
+    pub evm_address: [u8; 20],
+    pub timestamp: i64,
+}
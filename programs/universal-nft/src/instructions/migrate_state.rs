@@ -0,0 +1,367 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::Discriminator;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct MigrateState<'info> {
+    /// CHECK: Decoded by hand, older layouts are shorter than `ProgramState`
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump,
+        owner = crate::ID @ UniversalNftError::AccountValidationFailed
+    )]
+    pub program_state: UncheckedAccount<'info>,
+    
+    /// Created for deployments initialized before roles existed
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + Roles::INIT_SPACE,
+        seeds = [b"roles"],
+        bump
+    )]
+    pub roles: Account<'info, Roles>,
+    
+    /// Created for deployments initialized before the treasury existed
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + Treasury::INIT_SPACE,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// Created for deployments initialized before minting was configurable,
+    /// defaulting to free and unlimited minting as before
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + MintConfig::INIT_SPACE,
+        seeds = [b"mint_config"],
+        bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+    
+    /// Program authority, paying for the larger account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// `ProgramState` as deployed at version 1
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ProgramStateV1 {
+    pub authority: Pubkey,
+    pub gateway: Pubkey,
+    pub next_token_id: u64,
+    pub total_minted: u64,
+    pub total_transfers: u64,
+    pub total_receives: u64,
+    pub version: u8,
+    pub bump: u8,
+}
+
+impl ProgramStateV1 {
+    /// Current layout with every field added since version 1 at its default
+    pub fn upgrade(self) -> ProgramState {
+        ProgramState {
+            authority: self.authority,
+            gateway: self.gateway,
+            next_token_id: self.next_token_id,
+            total_minted: self.total_minted,
+            total_transfers: self.total_transfers,
+            total_receives: self.total_receives,
+            version: PROGRAM_STATE_VERSION,
+            bump: self.bump,
+            tss_address: [0u8; 20],
+            tss_nonce: 0,
+            pauser: Pubkey::default(),
+            paused: 0,
+            pending_gateway: Pubkey::default(),
+            gateway_update_eta: 0,
+            gateway_update_delay: DEFAULT_GATEWAY_UPDATE_DELAY,
+            pending_authority: Pubkey::default(),
+            pending_gateway_update_delay: 0,
+            pending_tss_address: [0u8; 20],
+            tss_update_eta: 0,
+            pending_pauser: Pubkey::default(),
+            pauser_update_eta: 0,
+        }
+    }
+}
+
+/// Decodes a stored program state of any supported version into the current
+/// layout. Fails if the account already has the current version.
+pub fn upgrade_program_state(data: &[u8]) -> Result<ProgramState> {
+    require!(
+        data.len() > 8 && data[..8] == ProgramState::DISCRIMINATOR,
+        UniversalNftError::AccountValidationFailed
+    );
+    
+    let mut fields = &data[8..];
+    let v1 = ProgramStateV1::deserialize(&mut fields)
+        .map_err(|_| UniversalNftError::AccountValidationFailed)?;
+    match v1.version {
+        1 => Ok(v1.upgrade()),
+        PROGRAM_STATE_VERSION => Err(UniversalNftError::StateAlreadyMigrated.into()),
+        _ => Err(UniversalNftError::UnsupportedStateVersion.into()),
+    }
+}
+
+/// Reallocates the program state to the current layout, filling defaults for
+/// the new fields, and bumps its version.
+pub fn migrate_state(ctx: Context<MigrateState>) -> Result<()> {
+    let account = ctx.accounts.program_state.to_account_info();
+    
+    let state = upgrade_program_state(&account.try_borrow_data()?)?;
+    require_keys_eq!(
+        state.authority,
+        ctx.accounts.authority.key(),
+        UniversalNftError::Unauthorized
+    );
+    
+    let old_len = account.data_len();
+    let new_len = 8 + ProgramState::INIT_SPACE;
+    msg!("Migrating program state to version {}", PROGRAM_STATE_VERSION);
+    msg!("Account size: {} -> {} bytes", old_len, new_len);
+    
+    resize_account(
+        &account,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program,
+        new_len,
+    )?;
+    let mut data = account.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    state.try_serialize(&mut writer)?;
+    
+    let roles = &mut ctx.accounts.roles;
+    roles.bump = ctx.bumps.roles;
+    ctx.accounts.treasury.bump = ctx.bumps.treasury;
+    ctx.accounts.mint_config.bump = ctx.bumps.mint_config;
+    
+    emit!(ProgramStateMigratedEvent {
+        from_version: 1,
+        to_version: PROGRAM_STATE_VERSION,
+        old_len: old_len as u32,
+        new_len: new_len as u32,
+        authority: ctx.accounts.authority.key(),
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct ProgramStateMigratedEvent {
+    pub from_version: u8,
+    pub to_version: u8,
+    pub old_len: u32,
+    pub new_len: u32,
+    pub authority: Pubkey,
+}
+
+/// Grows `account` to `new_len` bytes, topping it up from `payer` to stay
+/// rent exempt
+fn resize_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    new_len: usize,
+) -> Result<()> {
+    let required = Rent::get()?.minimum_balance(new_len);
+    let top_up = required.saturating_sub(account.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    
+    account.realloc(new_len, true)?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64)]
+pub struct MigrateChainConfig<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        seeds = [b"roles"],
+        bump = roles.bump,
+        constraint = roles.authorizes(&program_state.authority, Role::ChainManager, &authority.key()) @ UniversalNftError::Unauthorized
+    )]
+    pub roles: Account<'info, Roles>,
+    
+    /// CHECK: Decoded by hand, older layouts are shorter than `ChainConfig`
+    #[account(
+        mut,
+        seeds = [b"chain_config", chain_id.to_le_bytes().as_ref()],
+        bump,
+        owner = crate::ID @ UniversalNftError::AccountValidationFailed
+    )]
+    pub chain_config: UncheckedAccount<'info>,
+    
+    /// Authority or chain manager, paying for the larger account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// `ChainConfig` as written before revert gas limits were configurable
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ChainConfigV1 {
+    pub chain_id: u64,
+    pub codec: MessageCodec,
+    pub enabled: bool,
+    pub bump: u8,
+}
+
+/// `ChainConfig` as written before per-chain fees
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ChainConfigV2 {
+    pub chain_id: u64,
+    pub codec: MessageCodec,
+    pub enabled: bool,
+    pub min_revert_gas_limit: u64,
+    pub max_revert_gas_limit: u64,
+    pub bump: u8,
+}
+
+impl ChainConfigV2 {
+    /// Fee layout with fees at zero
+    fn upgrade(self) -> ChainConfigV3 {
+        ChainConfigV3 {
+            chain_id: self.chain_id,
+            codec: self.codec,
+            enabled: self.enabled,
+            min_revert_gas_limit: self.min_revert_gas_limit,
+            max_revert_gas_limit: self.max_revert_gas_limit,
+            gas_deposit: 0,
+            fee_lamports: 0,
+            fee_bps: 0,
+            bump: self.bump,
+        }
+    }
+}
+
+/// `ChainConfig` as written before the universal contract was recorded
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ChainConfigV3 {
+    pub chain_id: u64,
+    pub codec: MessageCodec,
+    pub enabled: bool,
+    pub min_revert_gas_limit: u64,
+    pub max_revert_gas_limit: u64,
+    pub gas_deposit: u64,
+    pub fee_lamports: u64,
+    pub fee_bps: u16,
+    pub bump: u8,
+}
+
+/// Decodes a stored chain config of an older layout, told apart by length,
+/// into the current one. Fees start at zero and the universal contract unset;
+/// a config without revert gas limits only accepts the default limit until it
+/// is reconfigured.
+pub fn upgrade_chain_config(data: &[u8]) -> Result<ChainConfig> {
+    require!(
+        data.len() > 8 && data[..8] == ChainConfig::DISCRIMINATOR,
+        UniversalNftError::AccountValidationFailed
+    );
+    
+    let fields = &data[8..];
+    let v3 = if fields.len() == 8 + 1 + 1 + 1 {
+        let v1 = ChainConfigV1::try_from_slice(fields)
+            .map_err(|_| UniversalNftError::AccountValidationFailed)?;
+        ChainConfigV2 {
+            chain_id: v1.chain_id,
+            codec: v1.codec,
+            enabled: v1.enabled,
+            min_revert_gas_limit: DEFAULT_ON_REVERT_GAS_LIMIT,
+            max_revert_gas_limit: DEFAULT_ON_REVERT_GAS_LIMIT,
+            bump: v1.bump,
+        }
+        .upgrade()
+    } else if fields.len() == 8 + 1 + 1 + 8 + 8 + 1 {
+        ChainConfigV2::try_from_slice(fields)
+            .map_err(|_| UniversalNftError::AccountValidationFailed)?
+            .upgrade()
+    } else if fields.len() == 8 + 1 + 1 + 8 + 8 + 8 + 8 + 2 + 1 {
+        ChainConfigV3::try_from_slice(fields)
+            .map_err(|_| UniversalNftError::AccountValidationFailed)?
+    } else if fields.len() == ChainConfig::INIT_SPACE {
+        return Err(UniversalNftError::ChainConfigAlreadyMigrated.into());
+    } else {
+        return Err(UniversalNftError::AccountValidationFailed.into());
+    };
+    
+    Ok(ChainConfig {
+        chain_id: v3.chain_id,
+        codec: v3.codec,
+        enabled: v3.enabled,
+        min_revert_gas_limit: v3.min_revert_gas_limit,
+        max_revert_gas_limit: v3.max_revert_gas_limit,
+        gas_deposit: v3.gas_deposit,
+        fee_lamports: v3.fee_lamports,
+        fee_bps: v3.fee_bps,
+        universal_contract: [0u8; 20],
+        bump: v3.bump,
+    })
+}
+
+/// Reallocates a chain config written by an older version of the program to
+/// the current layout
+pub fn migrate_chain_config(ctx: Context<MigrateChainConfig>, chain_id: u64) -> Result<()> {
+    let account = ctx.accounts.chain_config.to_account_info();
+    
+    let chain_config = upgrade_chain_config(&account.try_borrow_data()?)?;
+    require!(chain_config.chain_id == chain_id, UniversalNftError::InvalidChainId);
+    
+    let old_len = account.data_len();
+    let new_len = 8 + ChainConfig::INIT_SPACE;
+    msg!("Migrating chain config of chain {}", chain_id);
+    msg!("Account size: {} -> {} bytes", old_len, new_len);
+    
+    resize_account(
+        &account,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program,
+        new_len,
+    )?;
+    let mut data = account.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    chain_config.try_serialize(&mut writer)?;
+    
+    emit!(ChainConfigMigratedEvent {
+        chain_id,
+        old_len: old_len as u32,
+        new_len: new_len as u32,
+        authority: ctx.accounts.authority.key(),
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct ChainConfigMigratedEvent {
+    pub chain_id: u64,
+    pub old_len: u32,
+    pub new_len: u32,
+    pub authority: Pubkey,
+}
@@ -0,0 +1,174 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::Token;
+use mpl_token_metadata::types::{Creator, DataV2};
+use crate::state::*;
+use crate::errors::*;
+use crate::nft::{issue_native_nft, InboundNftAccounts};
+use crate::token_id::derive_token_id;
+
+/// Remaining accounts passed per NFT of a batch, in order:
+/// mint, token account, NFT origin, metadata, master edition, recipient
+pub const BATCH_MINT_ACCOUNTS_PER_ITEM: usize = 6;
+
+/// One NFT of a `mint_batch`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct BatchMintItem {
+    pub recipient: Pubkey,
+    pub name: String,
+    pub uri: String,
+}
+
+#[derive(Accounts)]
+pub struct MintBatch<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        seeds = [b"roles"],
+        bump = roles.bump,
+        constraint = roles.authorizes(&program_state.authority, Role::Minter, &payer.key()) @ UniversalNftError::Unauthorized
+    )]
+    pub roles: Account<'info, Roles>,
+
+    #[account(
+        mut,
+        seeds = [b"mint_config"],
+        bump = mint_config.bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+
+    /// CHECK: Mint authority PDA
+    #[account(
+        seeds = [b"mint_authority"],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// Authority or minter, paying for the new accounts
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    /// CHECK: Metaplex token metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+}
+
+/// Splits the remaining accounts of a batch into one group per item
+pub fn batch_account_groups<T>(accounts: &[T], items: usize) -> Result<std::slice::ChunksExact<'_, T>> {
+    require!(
+        items > 0 && accounts.len() == items * BATCH_MINT_ACCOUNTS_PER_ITEM,
+        UniversalNftError::InvalidBatch
+    );
+    Ok(accounts.chunks_exact(BATCH_MINT_ACCOUNTS_PER_ITEM))
+}
+
+/// Mints one NFT per item, sharing `symbol` and `creators`.
+///
+/// Meant for the authority or minter seeding a collection: the supply cap
+/// applies, but not the price, mint window, wallet limit or allowlist of
+/// the mint config. Token IDs are assigned consecutively from the next
+/// sequence number, and each item's accounts are passed as a group of
+/// remaining accounts, see `BATCH_MINT_ACCOUNTS_PER_ITEM`.
+pub fn mint_batch<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintBatch<'info>>,
+    symbol: String,
+    creators: Option<Vec<Creator>>,
+    items: Vec<BatchMintItem>,
+) -> Result<()> {
+    msg!("Minting batch of {} NFTs", items.len());
+
+    ctx.accounts.program_state.require_not_paused(PAUSE_MINT)?;
+    ctx.accounts.mint_config.check_supply(items.len() as u64)?;
+    let groups = batch_account_groups(ctx.remaining_accounts, items.len())?;
+
+    require!(!symbol.is_empty(), UniversalNftError::InvalidInstructionData);
+    require!(symbol.len() <= 10, UniversalNftError::InvalidInstructionData);
+    for item in items.iter() {
+        require!(!item.name.is_empty(), UniversalNftError::InvalidInstructionData);
+        require!(item.name.len() <= 32, UniversalNftError::InvalidInstructionData);
+    }
+    if let Some(ref creators_vec) = creators {
+        let total_share: u32 = creators_vec.iter().map(|creator| creator.share as u32).sum();
+        require!(total_share <= 100, UniversalNftError::InvalidInstructionData);
+    }
+
+    let first_sequence = ctx.accounts.program_state.next_token_id;
+    let accounts = &ctx.accounts;
+    for ((sequence, item), group) in (first_sequence..).zip(items.iter()).zip(groups) {
+        let [mint, token_account, nft_origin, metadata, master_edition, recipient] = group else {
+            return Err(UniversalNftError::InvalidBatch.into());
+        };
+        require_keys_eq!(recipient.key(), item.recipient, UniversalNftError::InvalidRecipient);
+
+        let token_id = derive_token_id(SOLANA_CHAIN_ID, ctx.program_id, sequence);
+        let mint_address = issue_native_nft(
+            &InboundNftAccounts {
+                program_id: ctx.program_id,
+                payer: accounts.payer.to_account_info(),
+                payer_seeds: None,
+                mint: mint.clone(),
+                token_account: token_account.clone(),
+                nft_origin: nft_origin.clone(),
+                metadata: metadata.clone(),
+                mint_authority: accounts.mint_authority.to_account_info(),
+                mint_authority_bump: ctx.bumps.mint_authority,
+                recipient: recipient.clone(),
+                token_program: accounts.token_program.to_account_info(),
+                associated_token_program: accounts.associated_token_program.to_account_info(),
+                system_program: accounts.system_program.to_account_info(),
+                rent: accounts.rent.to_account_info(),
+                token_metadata_program: accounts.token_metadata_program.to_account_info(),
+                master_edition: None,
+                locked_token_account: None,
+            },
+            master_edition,
+            &token_id,
+            DataV2 {
+                name: item.name.clone(),
+                symbol: symbol.clone(),
+                uri: item.uri.clone(),
+                seller_fee_basis_points: 0,
+                creators: creators.clone(),
+                collection: None,
+                uses: None,
+            },
+        )?;
+        msg!("Minted #{} to {}: {}", sequence, item.recipient, mint_address);
+    }
+
+    let count = items.len() as u64;
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.next_token_id += count;
+    program_state.total_minted += count;
+    ctx.accounts.mint_config.minted += count;
+
+    emit!(BatchMintedEvent {
+        first_sequence,
+        count: items.len() as u32,
+        minter: ctx.accounts.payer.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Batch minted, next token number: {}", ctx.accounts.program_state.next_token_id);
+
+    Ok(())
+}
+
+/// Token IDs of the batch are `derive_token_id(SOLANA_CHAIN_ID, program_id, n)`
+/// for `n` in `first_sequence..first_sequence + count`
+#[event]
+pub struct BatchMintedEvent {
+    pub first_sequence: u64,
+    pub count: u32,
+    pub minter: Pubkey,
+    pub timestamp: i64,
+}
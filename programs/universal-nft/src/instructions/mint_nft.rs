@@ -1,8 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo};
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Token, TokenAccount};
 use mpl_token_metadata::types::{DataV2, Creator};
-use mpl_token_metadata::instructions::{CreateMetadataAccountV3, CreateMasterEditionV3};
 use crate::state::*;
+use crate::errors::UniversalNftError;
+use crate::nft::{issue_native_nft, InboundNftAccounts};
+use crate::token_id::to_decimal_string;
 
 #[derive(Accounts)]
 pub struct MintNft<'info> {
@@ -13,31 +16,59 @@ pub struct MintNft<'info> {
     )]
     pub program_state: Account<'info, ProgramState>,
     
+    /// Once a minter is assigned, only it or the authority may mint
     #[account(
-        init,
-        payer = payer,
-        mint::decimals = 0,
-        mint::authority = mint_authority,
-        mint::freeze_authority = mint_authority,
+        seeds = [b"roles"],
+        bump = roles.bump,
+        constraint = roles.minter == Pubkey::default()
+            || roles.authorizes(&program_state.authority, Role::Minter, &payer.key())
+            @ crate::errors::UniversalNftError::Unauthorized
     )]
-    pub mint: Account<'info, Mint>,
+    pub roles: Account<'info, Roles>,
     
     #[account(
-        init,
-        payer = payer,
-        associated_token::mint = mint,
-        associated_token::authority = recipient,
+        mut,
+        seeds = [b"mint_config"],
+        bump = mint_config.bump
     )]
-    pub token_account: Account<'info, TokenAccount>,
+    pub mint_config: Account<'info, MintConfig>,
     
+    /// Counts the payer's mints against the wallet limit
     #[account(
-        init,
+        init_if_needed,
         payer = payer,
-        space = NftOrigin::LEN,
-        seeds = [b"nft_origin", mint.key().as_ref()],
+        space = 8 + MintRecord::INIT_SPACE,
+        seeds = [b"mint_record", payer.key().as_ref()],
         bump
     )]
-    pub nft_origin: Account<'info, NftOrigin>,
+    pub mint_record: Account<'info, MintRecord>,
+    
+    /// Claims page of the ticket redeemed, required while an allowlist is set
+    #[account(mut)]
+    pub allowlist_claims: Option<Box<Account<'info, AllowlistClaims>>>,
+    
+    /// Receives SOL proceeds when no split is configured
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// CHECK: Mint PDA of the token ID this mint will receive, created and
+    /// checked by `nft::issue_native_nft`
+    #[account(mut)]
+    pub mint: UncheckedAccount<'info>,
+    
+    /// CHECK: Recipient's associated token account, created and checked by
+    /// `nft::issue_native_nft`
+    #[account(mut)]
+    pub token_account: UncheckedAccount<'info>,
+    
+    /// CHECK: Origin record PDA of the mint, created and checked by
+    /// `nft::issue_native_nft`
+    #[account(mut)]
+    pub nft_origin: UncheckedAccount<'info>,
     
     /// CHECK: Metadata account will be created by Metaplex
     #[account(mut)]
@@ -69,28 +100,48 @@ pub struct MintNft<'info> {
     pub token_metadata_program: AccountInfo<'info>,
 }
 
-pub fn mint_nft(
-    ctx: Context<MintNft>,
+/// Mints a new NFT under the terms of the mint config.
+///
+/// A priced mint takes the payment accounts as remaining accounts: for SOL,
+/// one account per payee; for an SPL token, the payer's token account
+/// followed by one token account per payee. The payees are the proceeds
+/// recipients in order, or the treasury when a SOL price has no split.
+///
+/// While the mint config has an allowlist, the payer redeems one of its
+/// tickets with `allowlist_proof`.
+pub fn mint_nft<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintNft<'info>>,
     name: String,
     symbol: String,
     uri: String,
     creators: Option<Vec<Creator>>,
+    allowlist_proof: Option<AllowlistProof>,
 ) -> Result<()> {
     msg!("Minting NFT: {}", name);
 
+    ctx.accounts.program_state.require_not_paused(PAUSE_MINT)?;
+    ctx.accounts
+        .mint_config
+        .check_mint(Clock::get()?.unix_timestamp, ctx.accounts.mint_record.minted)?;
+    let merkle_root = ctx.accounts.mint_config.settings.merkle_root;
+    if merkle_root != [0u8; 32] {
+        let (Some(proof), Some(allowlist_claims)) = (&allowlist_proof, &mut ctx.accounts.allowlist_claims) else {
+            return Err(UniversalNftError::AllowlistProofRequired.into());
+        };
+        allowlist_claims.redeem(&merkle_root, &ctx.accounts.payer.key().to_bytes(), proof)?;
+        msg!("Redeemed allowlist ticket {}", proof.ticket);
+    }
+    collect_payment(ctx.accounts, ctx.remaining_accounts)?;
+
     // Validate inputs
     require!(!name.is_empty(), crate::errors::UniversalNftError::InvalidInstructionData);
     require!(!symbol.is_empty(), crate::errors::UniversalNftError::InvalidInstructionData);
     require!(name.len() <= 32, crate::errors::UniversalNftError::InvalidInstructionData);
     require!(symbol.len() <= 10, crate::errors::UniversalNftError::InvalidInstructionData);
 
-    let program_state = &mut ctx.accounts.program_state;
-    let mint = &ctx.accounts.mint;
-    let token_account = &ctx.accounts.token_account;
-    let nft_origin = &mut ctx.accounts.nft_origin;
-
-    // Generate unique token ID
-    let token_id = generate_token_id(&mint.key(), program_state)?;
+    // Derive the universal token ID from this deployment's next sequence number
+    let token_id = ctx.accounts.program_state.next_universal_token_id(*ctx.program_id);
+    msg!("Token ID: {}", to_decimal_string(&token_id));
 
     // Validate creators if provided
     if let Some(ref creators_vec) = creators {
@@ -101,121 +152,61 @@ pub fn mint_nft(
         require!(total_share <= 100, crate::errors::UniversalNftError::InvalidInstructionData);
     }
 
-    // Create metadata
-    let metadata_data = DataV2 {
-        name: name.clone(),
-        symbol: symbol.clone(),
-        uri: uri.clone(),
-        seller_fee_basis_points: 0,
-        creators,
-        collection: None,
-        uses: None,
-    };
-
-    // Create metadata account
-    let create_metadata_ix = CreateMetadataAccountV3 {
-        metadata: ctx.accounts.metadata.key(),
-        mint: mint.key(),
-        mint_authority: ctx.accounts.mint_authority.key(),
-        payer: ctx.accounts.payer.key(),
-        update_authority: (ctx.accounts.mint_authority.key(), true),
-        system_program: ctx.accounts.system_program.key(),
-        rent: Some(ctx.accounts.rent.key()),
-    };
-
-    let mint_authority_bump = ctx.bumps.mint_authority;
-    let mint_authority_seeds: &[&[u8]] = &[b"mint_authority", &[mint_authority_bump]];
-    let signer_seeds = &[mint_authority_seeds];
-
-    let meta_ix = create_metadata_ix.instruction(mpl_token_metadata::instructions::CreateMetadataAccountV3InstructionArgs {
-        data: metadata_data,
-        is_mutable: true,
-        collection_details: None,
-    });
-    
-    anchor_lang::solana_program::program::invoke_signed(
-        &meta_ix,
-        &[
-            ctx.accounts.metadata.to_account_info(),
-            ctx.accounts.mint.to_account_info(),
-            ctx.accounts.mint_authority.to_account_info(),
-            ctx.accounts.payer.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-            ctx.accounts.rent.to_account_info(),
-        ],
-        signer_seeds,
-    )?;
-
-    // Create master edition
-    let create_master_edition_ix = CreateMasterEditionV3 {
-        edition: ctx.accounts.master_edition.key(),
-        mint: mint.key(),
-        update_authority: ctx.accounts.mint_authority.key(),
-        mint_authority: ctx.accounts.mint_authority.key(),
-        payer: ctx.accounts.payer.key(),
-        metadata: ctx.accounts.metadata.key(),
-        token_program: ctx.accounts.token_program.key(),
-        system_program: ctx.accounts.system_program.key(),
-        rent: Some(ctx.accounts.rent.key()),
-    };
-
-    let master_ix = create_master_edition_ix.instruction(mpl_token_metadata::instructions::CreateMasterEditionV3InstructionArgs {
-        max_supply: Some(0),
-    });
-    
-    anchor_lang::solana_program::program::invoke_signed(
-        &master_ix,
-        &[
-            ctx.accounts.master_edition.to_account_info(),
-            ctx.accounts.mint.to_account_info(),
-            ctx.accounts.mint_authority.to_account_info(),
-            ctx.accounts.payer.to_account_info(),
-            ctx.accounts.metadata.to_account_info(),
-            ctx.accounts.token_program.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-            ctx.accounts.rent.to_account_info(),
-        ],
-        signer_seeds,
-    )?;
-
-    // Mint token to recipient
-    let mint_to_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        MintTo {
-            mint: ctx.accounts.mint.to_account_info(),
-            to: token_account.to_account_info(),
-            authority: ctx.accounts.mint_authority.to_account_info(),
+    let accounts = &ctx.accounts;
+    let mint = issue_native_nft(
+        &InboundNftAccounts {
+            program_id: ctx.program_id,
+            payer: accounts.payer.to_account_info(),
+            payer_seeds: None,
+            mint: accounts.mint.to_account_info(),
+            token_account: accounts.token_account.to_account_info(),
+            nft_origin: accounts.nft_origin.to_account_info(),
+            metadata: accounts.metadata.to_account_info(),
+            mint_authority: accounts.mint_authority.to_account_info(),
+            mint_authority_bump: ctx.bumps.mint_authority,
+            recipient: accounts.recipient.to_account_info(),
+            token_program: accounts.token_program.to_account_info(),
+            associated_token_program: accounts.associated_token_program.to_account_info(),
+            system_program: accounts.system_program.to_account_info(),
+            rent: accounts.rent.to_account_info(),
+            token_metadata_program: accounts.token_metadata_program.to_account_info(),
+            master_edition: None,
+            locked_token_account: None,
         },
-        signer_seeds,
-    );
-    
-    token::mint_to(mint_to_ctx, 1)?;
-
-    // Store origin information
-    let clock = Clock::get()?;
-    nft_origin.original_mint = mint.key();
-    nft_origin.token_id = token_id;
-    nft_origin.origin_chain_id = SOLANA_CHAIN_ID;
-    nft_origin.current_chain_id = SOLANA_CHAIN_ID;
-    nft_origin.block_number = clock.slot;
-    nft_origin.transfer_count = 0;
-    nft_origin.last_transfer_timestamp = clock.unix_timestamp;
-    nft_origin.bump = ctx.bumps.nft_origin;
+        &accounts.master_edition,
+        &token_id,
+        DataV2 {
+            name: name.clone(),
+            symbol: symbol.clone(),
+            uri: uri.clone(),
+            seller_fee_basis_points: 0,
+            creators,
+            collection: None,
+            uses: None,
+        },
+    )?;
 
     // Update program state
+    let program_state = &mut ctx.accounts.program_state;
     program_state.next_token_id += 1;
     program_state.total_minted += 1;
 
+    ctx.accounts.mint_config.minted += 1;
+    let mint_record = &mut ctx.accounts.mint_record;
+    mint_record.wallet = ctx.accounts.payer.key();
+    mint_record.minted += 1;
+    mint_record.bump = ctx.bumps.mint_record;
+
     // Emit mint event
     emit!(NftMintedEvent {
-        mint: mint.key(),
+        mint,
         token_id,
         recipient: ctx.accounts.recipient.key(),
         name,
         symbol,
         uri,
         origin_chain_id: SOLANA_CHAIN_ID,
-        timestamp: clock.unix_timestamp,
+        timestamp: Clock::get()?.unix_timestamp,
     });
 
     msg!("NFT minted successfully");
@@ -223,19 +214,90 @@ pub fn mint_nft(
     Ok(())
 }
 
-fn generate_token_id(mint: &Pubkey, program_state: &ProgramState) -> Result<[u8; 32]> {
-    let mut token_id = [0u8; 32];
-    let mint_bytes = mint.to_bytes();
-    let clock = Clock::get()?;
-    let block_number = clock.slot.to_le_bytes();
-    let next_id = program_state.next_token_id.to_le_bytes();
-    
-    // Combine mint pubkey + block number + next token ID
-    token_id[0..16].copy_from_slice(&mint_bytes[0..16]);
-    token_id[16..24].copy_from_slice(&block_number);
-    token_id[24..32].copy_from_slice(&next_id);
-    
-    Ok(token_id)
+/// Pays the mint price from the payer to the payees
+fn collect_payment<'info>(
+    accounts: &mut MintNft<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let settings = &accounts.mint_config.settings;
+    if settings.price == 0 {
+        return Ok(());
+    }
+    
+    let treasury_key = accounts.treasury.key();
+    let payees: Vec<(Pubkey, u64)> = if settings.proceeds.is_empty() {
+        vec![(treasury_key, settings.price)]
+    } else {
+        settings
+            .proceeds
+            .iter()
+            .map(|share| share.recipient)
+            .zip(settings.proceeds_amounts())
+            .collect()
+    };
+    
+    match settings.payment_mint {
+        None => {
+            require!(
+                remaining_accounts.len() == payees.len(),
+                UniversalNftError::InvalidPaymentAccount
+            );
+            for ((payee, amount), account) in payees.iter().zip(remaining_accounts) {
+                require_keys_eq!(account.key(), *payee, UniversalNftError::InvalidPaymentAccount);
+                system_program::transfer(
+                    CpiContext::new(
+                        accounts.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: accounts.payer.to_account_info(),
+                            to: account.clone(),
+                        },
+                    ),
+                    *amount,
+                )?;
+            }
+        }
+        Some(payment_mint) => {
+            require!(!settings.proceeds.is_empty(), UniversalNftError::ProceedsSplitRequired);
+            let (payer_account, payee_accounts) = remaining_accounts
+                .split_first()
+                .ok_or(UniversalNftError::InvalidPaymentAccount)?;
+            require!(
+                payee_accounts.len() == payees.len(),
+                UniversalNftError::InvalidPaymentAccount
+            );
+            check_token_account(payer_account, &payment_mint, &accounts.payer.key())?;
+            for ((payee, amount), account) in payees.iter().zip(payee_accounts) {
+                check_token_account(account, &payment_mint, payee)?;
+                token::transfer(
+                    CpiContext::new(
+                        accounts.token_program.to_account_info(),
+                        token::Transfer {
+                            from: payer_account.clone(),
+                            to: account.clone(),
+                            authority: accounts.payer.to_account_info(),
+                        },
+                    ),
+                    *amount,
+                )?;
+            }
+        }
+    }
+    
+    if settings.proceeds.is_empty() {
+        accounts.treasury.record_collected(settings.price)?;
+    }
+    msg!("Paid mint price: {}", settings.price);
+    
+    Ok(())
+}
+
+fn check_token_account(account: &AccountInfo, mint: &Pubkey, owner: &Pubkey) -> Result<()> {
+    require_keys_eq!(*account.owner, token::ID, UniversalNftError::InvalidPaymentAccount);
+    let token_account = TokenAccount::try_deserialize(&mut &account.try_borrow_data()?[..])
+        .map_err(|_| UniversalNftError::InvalidPaymentAccount)?;
+    require_keys_eq!(token_account.mint, *mint, UniversalNftError::InvalidPaymentAccount);
+    require_keys_eq!(token_account.owner, *owner, UniversalNftError::InvalidPaymentAccount);
+    Ok(())
 }
 
 #[event]
@@ -1,17 +1,45 @@
 pub mod initialize;
 pub mod mint_nft;
+pub mod mint_batch;
 pub mod transfer_cross_chain;
 pub mod receive_cross_chain;
 pub mod update_gateway;
 pub mod on_call;
 pub mod on_revert;
+pub mod on_abort;
+pub mod set_chain_config;
+pub mod link_evm_address;
+pub mod set_tss_address;
+pub mod execute_signed;
+pub mod pause;
+pub mod authority;
+pub mod set_role;
+pub mod migrate_state;
+pub mod withdraw_treasury;
+pub mod quote_transfer;
+pub mod set_mint_config;
+pub mod init_allowlist_claims;
 // pub mod trigger_deposit;
 
 pub use initialize::*;
 pub use mint_nft::*;
+pub use mint_batch::*;
 pub use transfer_cross_chain::*;
 pub use receive_cross_chain::*;
 pub use update_gateway::*;
 pub use on_call::*;
 pub use on_revert::*;
+pub use on_abort::*;
+pub use set_chain_config::*;
+pub use link_evm_address::*;
+pub use set_tss_address::*;
+pub use execute_signed::*;
+pub use pause::*;
+pub use authority::*;
+pub use set_role::*;
+pub use migrate_state::*;
+pub use withdraw_treasury::*;
+pub use quote_transfer::*;
+pub use set_mint_config::*;
+pub use init_allowlist_claims::*;
 // pub use trigger_deposit::*;
\ No newline at end of file
@@ -0,0 +1,345 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::{get_associated_token_address, AssociatedToken};
+use anchor_spl::token::{Mint, Token};
+use crate::state::*;
+use crate::errors::*;
+use crate::gateway::gateway_pda_address;
+use crate::nft::{self, NftCustody, RestoreNftAccounts};
+use super::on_revert::{parse_revert_data, refund_from_connected};
+
+/// Called by ZetaChain gateway when a cross-chain call is aborted, after
+/// even the revert could not be executed
+#[derive(Accounts)]
+pub struct OnAbort<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Program PDA credited by the gateway with any SOL returned on abort
+    #[account(
+        mut,
+        seeds = [b"connected"],
+        bump
+    )]
+    pub connected: SystemAccount<'info>,
+    
+    /// CHECK: Gateway PDA, must sign the call, see `gateway::verify_gateway_caller`.
+    /// Owned by the gateway program, so refunds never debit it.
+    #[account(
+        address = gateway_pda_address(&program_state.gateway) @ UniversalNftError::InvalidGatewayPda
+    )]
+    pub gateway_pda: UncheckedAccount<'info>,
+    
+    /// Transfer record of the aborted operation, closed into the revert
+    /// address once the NFT is restored
+    #[account(
+        mut,
+        seeds = [b"transfer", transfer_record.token_id.as_ref()],
+        bump = transfer_record.bump,
+        constraint = transfer_record.status == TransferStatus::InProgress @ UniversalNftError::TransferNotInProgress
+    )]
+    pub transfer_record: Account<'info, CrossChainTransfer>,
+    
+    /// CHECK: Refund destination, must match the revert address stored on the transfer record
+    #[account(
+        mut,
+        address = transfer_record.revert_address @ UniversalNftError::InvalidRevertAddress
+    )]
+    pub revert_address: UncheckedAccount<'info>,
+    
+    /// Mint of the aborted NFT, which gets its token back
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [b"nft_origin", mint.key().as_ref()],
+        bump = nft_origin.bump,
+        constraint = nft_origin.token_id == transfer_record.token_id @ UniversalNftError::TransferRecordMismatch
+    )]
+    pub nft_origin: Account<'info, NftOrigin>,
+    
+    /// CHECK: Owner the NFT is restored to, must be the sender on the transfer record
+    #[account(address = transfer_record.sender @ UniversalNftError::TransferRecordMismatch)]
+    pub original_owner: UncheckedAccount<'info>,
+    
+    /// CHECK: Original owner's associated token account, created if it was closed,
+    /// or holding the frozen NFT if it is Solana-native
+    #[account(
+        mut,
+        address = get_associated_token_address(&original_owner.key(), &mint.key()) @ UniversalNftError::AccountValidationFailed
+    )]
+    pub owner_token_account: UncheckedAccount<'info>,
+    
+    /// CHECK: Mint authority PDA, delegate of the frozen Solana-native NFTs
+    #[account(
+        mut,
+        seeds = [b"mint_authority"],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: Master edition of a Solana-native NFT, checked by `nft::thaw_nft`
+    pub master_edition: Option<UncheckedAccount<'info>>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    /// CHECK: Metaplex token metadata program, needed for Solana-native NFTs
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Instruction sysvar account for caller verification
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+    pub instruction_sysvar_account: UncheckedAccount<'info>,
+}
+
+/// Restores the NFT of an aborted transfer when the abort data identifies it.
+/// Otherwise the transfer is only marked Aborted, and the authority restores
+/// the NFT with `recover_aborted_transfer`.
+pub fn on_abort(
+    ctx: Context<OnAbort>,
+    amount: u64,
+    sender: Pubkey,
+    data: Vec<u8>,
+) -> Result<()> {
+    msg!("=== ON_ABORT HANDLER START ===");
+    msg!("Amount: {} lamports", amount);
+    msg!("Sender: {}", sender);
+    msg!("Data length: {} bytes", data.len());
+    
+    let token_id = ctx.accounts.transfer_record.token_id;
+    
+    // Abort data that does not name this transfer leaves the NFT to the
+    // authority. Failing instead would leave the transfer in progress forever.
+    let identified = parse_revert_data(&data).and_then(|revert_info| {
+        require!(revert_info.token_id == token_id, UniversalNftError::TransferRecordMismatch);
+        revert_info.check_accounts(
+            &ctx.accounts.transfer_record.key(),
+            &ctx.accounts.transfer_record,
+            &ctx.accounts.mint.key(),
+        )
+    });
+    let restored = match identified {
+        Ok(()) => true,
+        Err(e) => {
+            msg!("⚠️ Abort data does not identify this transfer ({:?}), leaving NFT for recovery", e);
+            false
+        }
+    };
+    
+    let connected_lamports_before = ctx.accounts.connected.lamports();
+    
+    // A failing restore CPI fails the whole abort, which the gateway can retry
+    if restored {
+        msg!("Restoring NFT to original owner: {}", ctx.accounts.original_owner.key());
+        let connected_seeds: &[&[u8]] = &[b"connected", &[ctx.bumps.connected]];
+        nft::restore_nft(&RestoreNftAccounts {
+            payer: ctx.accounts.connected.to_account_info(),
+            payer_seeds: Some(connected_seeds),
+            mint: ctx.accounts.mint.to_account_info(),
+            owner: ctx.accounts.original_owner.to_account_info(),
+            owner_token_account: ctx.accounts.owner_token_account.to_account_info(),
+            mint_authority: ctx.accounts.mint_authority.to_account_info(),
+            mint_authority_bump: ctx.bumps.mint_authority,
+            token_program: ctx.accounts.token_program.to_account_info(),
+            associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            master_edition: ctx.accounts.master_edition.as_ref().map(|account| account.to_account_info()),
+            token_metadata_program: ctx
+                .accounts
+                .token_metadata_program
+                .as_ref()
+                .map(|account| account.to_account_info()),
+        }, NftCustody::of(&ctx.accounts.nft_origin))?;
+        
+        let nft_origin = &mut ctx.accounts.nft_origin;
+        nft_origin.current_chain_id = SOLANA_CHAIN_ID;
+        nft_origin.last_transfer_timestamp = Clock::get()?.unix_timestamp;
+    }
+    
+    // Return whatever SOL came back with the abort to the revert address
+    let spent = connected_lamports_before.saturating_sub(ctx.accounts.connected.lamports());
+    let refund = amount.saturating_sub(spent);
+    if refund > 0 {
+        refund_from_connected(
+            &ctx.accounts.connected,
+            ctx.bumps.connected,
+            &ctx.accounts.revert_address.to_account_info(),
+            &ctx.accounts.system_program,
+            refund,
+        )?;
+    }
+    
+    let timestamp = Clock::get()?.unix_timestamp;
+    let transfer_record = &mut ctx.accounts.transfer_record;
+    transfer_record.status = TransferStatus::Aborted;
+    transfer_record.completed_at = Some(timestamp);
+    if !restored {
+        transfer_record.error_message = Some("Aborted, NFT awaiting recovery".to_string());
+    }
+    let transfer_record_key = transfer_record.key();
+    
+    // A restored NFT can be transferred again, which needs a fresh record
+    if restored {
+        ctx.accounts
+            .transfer_record
+            .close(ctx.accounts.revert_address.to_account_info())?;
+    }
+    
+    ctx.accounts.program_state.total_receives += 1;
+    
+    emit!(CrossChainAbortEvent {
+        token_id,
+        transfer_record: transfer_record_key,
+        original_owner: ctx.accounts.original_owner.key(),
+        restored,
+        refund,
+        timestamp,
+    });
+    
+    msg!("✅ on_abort completed, NFT restored: {}", restored);
+    msg!("=== ON_ABORT HANDLER END ===");
+    
+    Ok(())
+}
+
+/// Restores the NFT of a transfer left Aborted by `on_abort` (admin only)
+#[derive(Accounts)]
+pub struct RecoverAbortedTransfer<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNftError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Pays for recreating the owner's token account if it was closed
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Transfer record of the aborted operation, closed into the revert
+    /// address once the NFT is restored
+    #[account(
+        mut,
+        seeds = [b"transfer", transfer_record.token_id.as_ref()],
+        bump = transfer_record.bump,
+        close = revert_address,
+        constraint = transfer_record.status == TransferStatus::Aborted @ UniversalNftError::TransferNotAborted
+    )]
+    pub transfer_record: Account<'info, CrossChainTransfer>,
+    
+    /// CHECK: Receives the transfer record's rent, must match the revert address stored on it
+    #[account(
+        mut,
+        address = transfer_record.revert_address @ UniversalNftError::InvalidRevertAddress
+    )]
+    pub revert_address: UncheckedAccount<'info>,
+    
+    /// Mint of the aborted NFT, which gets its token back
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [b"nft_origin", mint.key().as_ref()],
+        bump = nft_origin.bump,
+        constraint = nft_origin.token_id == transfer_record.token_id @ UniversalNftError::TransferRecordMismatch
+    )]
+    pub nft_origin: Account<'info, NftOrigin>,
+    
+    /// CHECK: Owner the NFT is restored to, must be the sender on the transfer record
+    #[account(address = transfer_record.sender @ UniversalNftError::TransferRecordMismatch)]
+    pub original_owner: UncheckedAccount<'info>,
+    
+    /// CHECK: Original owner's associated token account, created if it was closed,
+    /// or holding the frozen NFT if it is Solana-native
+    #[account(
+        mut,
+        address = get_associated_token_address(&original_owner.key(), &mint.key()) @ UniversalNftError::AccountValidationFailed
+    )]
+    pub owner_token_account: UncheckedAccount<'info>,
+    
+    /// CHECK: Mint authority PDA, delegate of the frozen Solana-native NFTs
+    #[account(
+        mut,
+        seeds = [b"mint_authority"],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: Master edition of a Solana-native NFT, checked by `nft::thaw_nft`
+    pub master_edition: Option<UncheckedAccount<'info>>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    /// CHECK: Metaplex token metadata program, needed for Solana-native NFTs
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: Option<UncheckedAccount<'info>>,
+}
+
+/// Gives the NFT of an Aborted transfer back to its original owner and
+/// closes the transfer record, so the token can be transferred again
+pub fn recover_aborted_transfer(ctx: Context<RecoverAbortedTransfer>) -> Result<()> {
+    let token_id = ctx.accounts.transfer_record.token_id;
+    
+    nft::restore_nft(&RestoreNftAccounts {
+        payer: ctx.accounts.authority.to_account_info(),
+        payer_seeds: None,
+        mint: ctx.accounts.mint.to_account_info(),
+        owner: ctx.accounts.original_owner.to_account_info(),
+        owner_token_account: ctx.accounts.owner_token_account.to_account_info(),
+        mint_authority: ctx.accounts.mint_authority.to_account_info(),
+        mint_authority_bump: ctx.bumps.mint_authority,
+        token_program: ctx.accounts.token_program.to_account_info(),
+        associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        master_edition: ctx.accounts.master_edition.as_ref().map(|account| account.to_account_info()),
+        token_metadata_program: ctx
+            .accounts
+            .token_metadata_program
+            .as_ref()
+            .map(|account| account.to_account_info()),
+    }, NftCustody::of(&ctx.accounts.nft_origin))?;
+    
+    let timestamp = Clock::get()?.unix_timestamp;
+    let nft_origin = &mut ctx.accounts.nft_origin;
+    nft_origin.current_chain_id = SOLANA_CHAIN_ID;
+    nft_origin.last_transfer_timestamp = timestamp;
+    
+    msg!("NFT of aborted transfer restored to {}", ctx.accounts.original_owner.key());
+    
+    emit!(AbortedTransferRecoveredEvent {
+        token_id,
+        transfer_record: ctx.accounts.transfer_record.key(),
+        original_owner: ctx.accounts.original_owner.key(),
+        authority: ctx.accounts.authority.key(),
+        timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct CrossChainAbortEvent {
+    pub token_id: [u8; 32],
+    pub transfer_record: Pubkey,
+    pub original_owner: Pubkey,
+    /// False when the NFT awaits `recover_aborted_transfer`
+    pub restored: bool,
+    pub refund: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AbortedTransferRecoveredEvent {
+    pub token_id: [u8; 32],
+    pub transfer_record: Pubkey,
+    pub original_owner: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
@@ -1,6 +1,14 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::Token;
 use crate::state::*;
 use crate::errors::*;
+use crate::gateway::gateway_pda_address;
+use crate::message::{decode_allowlist_claim, decode_inbound_message, is_allowlist_claim};
+use crate::merkle::evm_claimant;
+use crate::abi::decode_inbound_payload;
+use crate::nft::{create_pda_account, inbound_metadata, issue_inbound_nft, issue_native_nft, InboundNftAccounts};
 
 /// Called by ZetaChain gateway when receiving cross-chain calls
 #[derive(Accounts)]
@@ -12,15 +20,99 @@ pub struct OnCall<'info> {
     )]
     pub program_state: Account<'info, ProgramState>,
     
-    /// CHECK: Gateway PDA account
-    #[account(mut)]
+    /// Settings of ZetaChain, where every gateway call originates, selecting
+    /// the message codec
+    #[account(
+        seeds = [b"chain_config", ZETACHAIN_CHAIN_ID.to_le_bytes().as_ref()],
+        bump = chain_config.bump,
+        constraint = chain_config.enabled @ UniversalNftError::CrossChainNotSupported
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+    
+    /// Program PDA credited by the gateway with the SOL sent alongside the call
+    #[account(
+        mut,
+        seeds = [b"connected"],
+        bump
+    )]
+    pub connected: SystemAccount<'info>,
+    
+    /// CHECK: Gateway PDA, must sign the call, see `gateway::verify_gateway_caller`
+    #[account(
+        address = gateway_pda_address(&program_state.gateway) @ UniversalNftError::InvalidGatewayPda
+    )]
     pub gateway_pda: UncheckedAccount<'info>,
     
-    /// CHECK: Random wallet for testing (as per ZetaChain example)
+    /// CHECK: NFT and SOL recipient, validated against the message recipient
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// Minting terms, required for allowlist claims
+    #[account(
+        mut,
+        seeds = [b"mint_config"],
+        bump = mint_config.bump
+    )]
+    pub mint_config: Option<Account<'info, MintConfig>>,
+    
+    /// Claims page of the ticket redeemed by an allowlist claim
+    #[account(mut)]
+    pub allowlist_claims: Option<Box<Account<'info, AllowlistClaims>>>,
+    
+    /// CHECK: Mint record PDA of the claimant of an allowlist claim, created on
+    /// its first claim, see `claimant_minted`
+    #[account(mut)]
+    pub mint_record: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Mint PDA of the message token ID, created on first arrival
+    #[account(mut)]
+    pub mint: UncheckedAccount<'info>,
+    
+    /// CHECK: Recipient's associated token account, created if missing
+    #[account(mut)]
+    pub token_account: UncheckedAccount<'info>,
+    
+    /// CHECK: Origin record PDA of the mint, created on first arrival
+    #[account(mut)]
+    pub nft_origin: UncheckedAccount<'info>,
+    
+    /// CHECK: Metadata account will be created by Metaplex
     #[account(mut)]
-    pub random_wallet: UncheckedAccount<'info>,
+    pub metadata: UncheckedAccount<'info>,
     
+    /// CHECK: Mint authority PDA, delegate of the frozen Solana-native NFTs
+    #[account(
+        mut,
+        seeds = [b"mint_authority"],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: Master edition of a returning Solana-native NFT, checked by
+    /// `nft::thaw_nft`, or of the NFT minted for an allowlist claim, created
+    /// by Metaplex
+    #[account(mut)]
+    pub master_edition: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Token account a returning Solana-native NFT was frozen in,
+    /// checked by `nft::thaw_nft`
+    #[account(mut)]
+    pub locked_token_account: Option<UncheckedAccount<'info>>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    /// CHECK: Metaplex token metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
     
     /// CHECK: Instruction sysvar account for caller verification
     #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
@@ -38,17 +130,31 @@ pub fn on_call(
     msg!("Sender (EVM address): {:?}", sender);
     msg!("Data length: {} bytes", data.len());
     
-    let program_state = &mut ctx.accounts.program_state;
+    // Failing here makes the gateway revert the call back to its source chain
+    ctx.accounts.program_state.require_not_paused(PAUSE_INBOUND)?;
     
-    // Parse the cross-chain message from data
-    let message = parse_cross_chain_message(&data)?;
+    // Decode the cross-chain message, rejecting anything malformed. Allowlist
+    // claims have their own envelope type and become a mint of a new NFT.
+    let is_claim = is_allowlist_claim(&data);
+    let message = if is_claim {
+        redeem_allowlist_claim(&mut ctx, &sender, &data)?
+    } else {
+        decode_message(&ctx.accounts.chain_config, &data)?
+    };
     msg!("Parsed message type: {:?}", message.message_type);
     msg!("Token ID: {:?}", message.token_id);
     msg!("Source chain: {}", message.source_chain_id);
     msg!("Destination chain: {}", message.destination_chain_id);
     
+    // Accounts created for the NFT are paid from the SOL sent with the call
+    let connected_lamports_before = ctx.accounts.connected.lamports();
+    
     // Handle different message types
     match message.message_type {
+        CrossChainMessageType::Mint if is_claim => {
+            msg!("Processing allowlist claim mint");
+            handle_allowlist_mint(&mut ctx, &message)?;
+        },
         CrossChainMessageType::Mint => {
             msg!("Processing cross-chain NFT mint request");
             handle_cross_chain_mint(&mut ctx, &message, amount)?;
@@ -67,105 +173,203 @@ pub fn on_call(
         }
     }
     
-    // Transfer some portion of lamports to random wallet (as per ZetaChain example)
-    if amount > 0 {
-        let transfer_amount = amount / 2;
-        msg!("Transferring {} lamports to random wallet", transfer_amount);
-        
-        **ctx.accounts.gateway_pda.to_account_info().try_borrow_mut_lamports()? -= transfer_amount;
-        **ctx.accounts.random_wallet.to_account_info().try_borrow_mut_lamports()? += transfer_amount;
-    }
+    let spent = connected_lamports_before.saturating_sub(ctx.accounts.connected.lamports());
+    require!(spent <= amount, UniversalNftError::InsufficientInboundFunds);
+    msg!("Spent {} lamports on account creation", spent);
     
-    // Check if the message contains "revert" and return an error if so (for testing)
-    if let Ok(message_str) = String::from_utf8(data.clone()) {
-        if message_str.contains("revert") {
-            msg!("Revert message detected: '{}'", message_str);
-            return Err(UniversalNftError::RevertMessage.into());
-        }
+    // Forward the SOL that arrived with the message, less what was spent
+    let remaining = amount - spent;
+    if remaining > 0 {
+        route_inbound_funds(&mut ctx, &message, remaining)?;
     }
     
+    // Debugging hook that forces a revert (test-hooks builds only)
+    #[cfg(feature = "test-hooks")]
+    check_test_revert_trigger(&data)?;
+    
     msg!("✅ on_call completed successfully");
     msg!("=== ON_CALL HANDLER END ===");
     
     Ok(())
 }
 
-fn parse_cross_chain_message(data: &[u8]) -> Result<CrossChainMessage> {
-    msg!("Parsing cross-chain message from {} bytes", data.len());
-    
-    if data.is_empty() {
-        msg!("Empty data received, creating default transfer message");
-        // Create a default message for testing
-        return Ok(CrossChainMessage {
-            message_type: CrossChainMessageType::Transfer,
-            token_id: [0u8; 32],
-            source_chain_id: 1, // Ethereum
-            destination_chain_id: SOLANA_CHAIN_ID,
-            sender: [0u8; 20],
-            recipient: [0u8; 32],
-            metadata: NftMetadata {
-                name: "Cross-Chain NFT".to_string(),
-                symbol: "XNFT".to_string(),
-                uri: "https://example.com/metadata.json".to_string(),
-                seller_fee_basis_points: 0,
-                creators: None,
-                collection: None,
-            },
-            timestamp: Clock::get()?.unix_timestamp,
-        });
+/// Decodes inbound data with the codec configured for ZetaChain
+pub(crate) fn decode_message(chain_config: &ChainConfig, data: &[u8]) -> Result<CrossChainMessage> {
+    msg!("Decoding {:?} message from chain {}", chain_config.codec, chain_config.chain_id);
+    
+    match chain_config.codec {
+        MessageCodec::Borsh => decode_inbound_message(data, SOLANA_CHAIN_ID),
+        MessageCodec::Abi => Ok(decode_inbound_payload(data, SOLANA_CHAIN_ID)?
+            .into_message(chain_config.chain_id, Clock::get()?.unix_timestamp)),
     }
+}
+
+/// Redeems an allowlist ticket of the claimant named by the universal
+/// contract and returns the mint message of the new NFT it pays for
+fn redeem_allowlist_claim(
+    ctx: &mut Context<OnCall>,
+    sender: &[u8; 20],
+    data: &[u8],
+) -> Result<CrossChainMessage> {
+    let claim = decode_allowlist_claim(data)?;
+    msg!("Processing allowlist claim of ticket {}", claim.proof.ticket);
     
-    // Try to deserialize as CrossChainMessage
-    match CrossChainMessage::try_from_slice(data) {
-        Ok(message) => {
-            msg!("Successfully parsed structured message");
-            Ok(message)
-        },
-        Err(_) => {
-            msg!("Failed to parse as structured message, treating as raw data");
-            // If parsing fails, create a basic message from raw data
-            let message_str = String::from_utf8(data.to_vec())
-                .map_err(|_| UniversalNftError::InvalidDataFormat)?;
-            
-            msg!("Raw message: {}", message_str);
-            
-            // Create a basic cross-chain message
-            Ok(CrossChainMessage {
-                message_type: CrossChainMessageType::Transfer,
-                token_id: generate_token_id_from_data(data),
-                source_chain_id: 1, // Assume Ethereum
-                destination_chain_id: SOLANA_CHAIN_ID,
-                sender: [0u8; 20],
-                recipient: [0u8; 32],
-                metadata: NftMetadata {
-                    name: format!("Cross-Chain NFT: {}", message_str.chars().take(20).collect::<String>()),
-                    symbol: "XNFT".to_string(),
-                    uri: "https://example.com/metadata.json".to_string(),
-                    seller_fee_basis_points: 0,
-                    creators: None,
-                    collection: None,
-                },
-                timestamp: Clock::get()?.unix_timestamp,
-            })
+    // The gateway caller is the universal contract relaying the claim, so the
+    // claimant it names is only trusted from the configured contract
+    ctx.accounts.chain_config.check_claim_sender(sender)?;
+    
+    ctx.accounts.program_state.require_not_paused(PAUSE_MINT)?;
+    let claimant = evm_claimant(&claim.claimant);
+    let wallet_minted = claimant_minted(ctx, &claimant)?;
+    let (Some(mint_config), Some(allowlist_claims)) =
+        (&mut ctx.accounts.mint_config, &mut ctx.accounts.allowlist_claims)
+    else {
+        return Err(UniversalNftError::AllowlistProofRequired.into());
+    };
+    
+    let merkle_root = mint_config.settings.merkle_root;
+    require!(merkle_root != [0u8; 32], UniversalNftError::AllowlistProofRequired);
+    require!(mint_config.settings.price == 0, UniversalNftError::InboundClaimNotFree);
+    mint_config.check_mint(Clock::get()?.unix_timestamp, wallet_minted)?;
+    
+    allowlist_claims.redeem(&merkle_root, &claimant, &claim.proof)?;
+    mint_config.minted += 1;
+    
+    // The claimed NFT originates on Solana, numbered like `mint_nft`
+    let program_state = &mut ctx.accounts.program_state;
+    let token_id = program_state.next_universal_token_id(*ctx.program_id);
+    program_state.next_token_id += 1;
+    
+    emit!(AllowlistClaimedEvent {
+        claimant,
+        ticket: claim.proof.ticket,
+        token_id,
+        recipient: Pubkey::new_from_array(claim.recipient),
+        source_chain_id: ctx.accounts.chain_config.chain_id,
+    });
+    
+    Ok(CrossChainMessage {
+        message_type: CrossChainMessageType::Mint,
+        token_id,
+        source_chain_id: ctx.accounts.chain_config.chain_id,
+        destination_chain_id: SOLANA_CHAIN_ID,
+        sender: claim.claimant,
+        recipient: claim.recipient,
+        metadata: claim.metadata,
+        timestamp: Clock::get()?.unix_timestamp,
+        origin_chain_id: SOLANA_CHAIN_ID,
+    })
+}
+
+/// NFTs `claimant` has minted through allowlist claims, read from its mint
+/// record, which does not exist before its first claim
+fn claimant_minted(ctx: &Context<OnCall>, claimant: &[u8; 32]) -> Result<u32> {
+    let mint_record = ctx
+        .accounts
+        .mint_record
+        .as_ref()
+        .ok_or(UniversalNftError::AccountValidationFailed)?;
+    let (address, _) = Pubkey::find_program_address(&[b"mint_record", claimant.as_ref()], ctx.program_id);
+    require_keys_eq!(mint_record.key(), address, UniversalNftError::AccountValidationFailed);
+    
+    if mint_record.data_is_empty() {
+        return Ok(0);
+    }
+    require_keys_eq!(*mint_record.owner, *ctx.program_id, UniversalNftError::AccountValidationFailed);
+    Ok(MintRecord::try_deserialize(&mut &mint_record.try_borrow_data()?[..])?.minted)
+}
+
+/// Fails the call when the raw data contains "revert" so the gateway
+/// revert path can be exercised end to end
+#[cfg(feature = "test-hooks")]
+fn check_test_revert_trigger(data: &[u8]) -> Result<()> {
+    if let Ok(message_str) = std::str::from_utf8(data) {
+        if message_str.contains("revert") {
+            msg!("Revert message detected: '{}'", message_str);
+            return Err(UniversalNftError::RevertMessage.into());
+        }
+    }
+    Ok(())
+}
+
+/// Destination of SOL that arrives with an inbound message
+#[derive(Clone, Debug, PartialEq)]
+pub enum FundRoute {
+    /// Credit the NFT recipient named in the message
+    Recipient(Pubkey),
+    /// Credit the program treasury
+    Treasury,
+}
+
+/// Decides where SOL sent with an inbound message is credited.
+///
+/// Mint and transfer messages forward the deposit to the NFT recipient.
+/// Messages without a Solana recipient (reverts, burns, or a zero recipient)
+/// credit the program treasury as fees.
+pub fn fund_route_for(message: &CrossChainMessage) -> FundRoute {
+    match message.message_type {
+        CrossChainMessageType::Mint | CrossChainMessageType::Transfer
+            if message.recipient != [0u8; 32] =>
+        {
+            FundRoute::Recipient(Pubkey::new_from_array(message.recipient))
         }
+        _ => FundRoute::Treasury,
     }
 }
 
-fn generate_token_id_from_data(data: &[u8]) -> [u8; 32] {
-    let mut token_id = [0u8; 32];
-    let clock = Clock::get().unwrap();
+fn route_inbound_funds(
+    ctx: &mut Context<OnCall>,
+    message: &CrossChainMessage,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts.connected.lamports() >= amount,
+        UniversalNftError::InsufficientInboundFunds
+    );
     
-    // Combine data hash + timestamp + slot for uniqueness
-    let data_len = std::cmp::min(data.len(), 16);
-    token_id[0..data_len].copy_from_slice(&data[0..data_len]);
+    let route = fund_route_for(message);
+    let destination = match route {
+        FundRoute::Recipient(recipient) => {
+            require!(
+                ctx.accounts.recipient.key() == recipient,
+                UniversalNftError::InvalidRecipient
+            );
+            ctx.accounts.recipient.to_account_info()
+        }
+        FundRoute::Treasury => ctx.accounts.treasury.to_account_info(),
+    };
     
-    let timestamp_bytes = clock.unix_timestamp.to_le_bytes();
-    token_id[16..24].copy_from_slice(&timestamp_bytes);
+    msg!("Forwarding {} lamports to {}", amount, destination.key());
     
-    let slot_bytes = clock.slot.to_le_bytes();
-    token_id[24..32].copy_from_slice(&slot_bytes);
+    let connected_bump = ctx.bumps.connected;
+    let connected_seeds: &[&[u8]] = &[b"connected", &[connected_bump]];
+    let signer_seeds = &[connected_seeds];
     
-    token_id
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.connected.to_account_info(),
+                to: destination.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+    
+    if route == FundRoute::Treasury {
+        ctx.accounts.treasury.record_collected(amount)?;
+    }
+    
+    emit!(InboundFundsRoutedEvent {
+        token_id: message.token_id,
+        destination: destination.key(),
+        to_treasury: route == FundRoute::Treasury,
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
 }
 
 fn handle_cross_chain_mint(
@@ -174,18 +378,15 @@ fn handle_cross_chain_mint(
     _amount: u64,
 ) -> Result<()> {
     msg!("Handling cross-chain mint for token ID: {:?}", message.token_id);
-    
-    // In a full implementation, this would:
-    // 1. Create a new mint account
-    // 2. Create metadata account
-    // 3. Mint the NFT to the recipient
-    // 4. Store origin information
-    
-    // For now, just log the operation
-    msg!("Cross-chain mint would create NFT: {}", message.metadata.name);
+    msg!("Minting NFT: {}", message.metadata.name);
     msg!("Recipient: {:?}", message.recipient);
     
+    let is_returning_nft = issue_nft(ctx, message)?;
+    
     let program_state = &mut ctx.accounts.program_state;
+    if !is_returning_nft {
+        program_state.total_minted += 1;
+    }
     program_state.total_receives += 1;
     
     Ok(())
@@ -197,23 +398,113 @@ fn handle_cross_chain_transfer(
     _amount: u64,
 ) -> Result<()> {
     msg!("Handling cross-chain transfer for token ID: {:?}", message.token_id);
+    msg!("Moving NFT: {}", message.metadata.name);
+    msg!("From chain {} to chain {}", message.source_chain_id, message.destination_chain_id);
     
-    // In a full implementation, this would:
-    // 1. Find the existing NFT by token ID
-    // 2. Verify ownership
-    // 3. Update the NFT's location/ownership
-    // 4. Emit transfer event
+    let is_returning_nft = issue_nft(ctx, message)?;
     
-    // For now, just log the operation
-    msg!("Cross-chain transfer would move NFT: {}", message.metadata.name);
-    msg!("From chain {} to chain {}", message.source_chain_id, message.destination_chain_id);
+    let program_state = &mut ctx.accounts.program_state;
+    if !is_returning_nft {
+        program_state.total_minted += 1;
+    }
+    program_state.total_receives += 1;
+    
+    Ok(())
+}
+
+/// Mints the Solana-native NFT paid for by an allowlist claim, with a master
+/// edition like `mint_nft`, and counts it against the claimant's wallet limit
+fn handle_allowlist_mint(ctx: &mut Context<OnCall>, message: &CrossChainMessage) -> Result<()> {
+    msg!("Minting NFT: {}", message.metadata.name);
+    msg!("Recipient: {:?}", message.recipient);
+    
+    let connected_seeds: &[&[u8]] = &[b"connected", &[ctx.bumps.connected]];
+    let accounts = inbound_accounts(ctx, connected_seeds);
+    require_keys_eq!(
+        accounts.recipient.key(),
+        Pubkey::new_from_array(message.recipient),
+        UniversalNftError::InvalidRecipient
+    );
+    let master_edition = ctx
+        .accounts
+        .master_edition
+        .as_ref()
+        .ok_or(UniversalNftError::AccountValidationFailed)?
+        .to_account_info();
+    issue_native_nft(&accounts, &master_edition, &message.token_id, inbound_metadata(message))?;
+    
+    // The record address was checked against the claimant by `claimant_minted`
+    let claimant = evm_claimant(&message.sender);
+    let mint_record = ctx
+        .accounts
+        .mint_record
+        .as_ref()
+        .ok_or(UniversalNftError::AccountValidationFailed)?
+        .to_account_info();
+    let mut record = if mint_record.data_is_empty() {
+        let (_, bump) = Pubkey::find_program_address(&[b"mint_record", claimant.as_ref()], ctx.program_id);
+        create_pda_account(
+            &accounts,
+            &mint_record,
+            &[b"mint_record", claimant.as_ref(), &[bump]],
+            8 + MintRecord::INIT_SPACE,
+            ctx.program_id,
+        )?;
+        MintRecord {
+            wallet: Pubkey::new_from_array(claimant),
+            minted: 0,
+            bump,
+        }
+    } else {
+        MintRecord::try_deserialize(&mut &mint_record.try_borrow_data()?[..])?
+    };
+    record.minted += 1;
+    record.try_serialize(&mut &mut mint_record.try_borrow_mut_data()?[..])?;
     
     let program_state = &mut ctx.accounts.program_state;
+    program_state.total_minted += 1;
     program_state.total_receives += 1;
     
     Ok(())
 }
 
+/// Mints the message's NFT to its recipient, paid from the connected PDA
+fn issue_nft(ctx: &Context<OnCall>, message: &CrossChainMessage) -> Result<bool> {
+    let connected_seeds: &[&[u8]] = &[b"connected", &[ctx.bumps.connected]];
+    issue_inbound_nft(&inbound_accounts(ctx, connected_seeds), message)
+}
+
+/// Accounts of an NFT issued on an inbound call, paid by the connected PDA
+fn inbound_accounts<'a, 'info>(
+    ctx: &'a Context<'_, '_, '_, '_, OnCall<'info>>,
+    connected_seeds: &'a [&'a [u8]],
+) -> InboundNftAccounts<'a, 'info> {
+    let accounts = &ctx.accounts;
+    
+    InboundNftAccounts {
+        program_id: ctx.program_id,
+        payer: accounts.connected.to_account_info(),
+        payer_seeds: Some(connected_seeds),
+        mint: accounts.mint.to_account_info(),
+        token_account: accounts.token_account.to_account_info(),
+        nft_origin: accounts.nft_origin.to_account_info(),
+        metadata: accounts.metadata.to_account_info(),
+        mint_authority: accounts.mint_authority.to_account_info(),
+        mint_authority_bump: ctx.bumps.mint_authority,
+        recipient: accounts.recipient.to_account_info(),
+        token_program: accounts.token_program.to_account_info(),
+        associated_token_program: accounts.associated_token_program.to_account_info(),
+        system_program: accounts.system_program.to_account_info(),
+        rent: accounts.rent.to_account_info(),
+        token_metadata_program: accounts.token_metadata_program.to_account_info(),
+        master_edition: accounts.master_edition.as_ref().map(|account| account.to_account_info()),
+        locked_token_account: accounts
+            .locked_token_account
+            .as_ref()
+            .map(|account| account.to_account_info()),
+    }
+}
+
 fn handle_cross_chain_revert(
     ctx: &mut Context<OnCall>,
     message: &CrossChainMessage,
@@ -234,4 +525,23 @@ fn handle_cross_chain_revert(
     program_state.total_receives += 1;
     
     Ok(())
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct AllowlistClaimedEvent {
+    /// Claimant address as it appears in the allowlist tree
+    pub claimant: [u8; 32],
+    pub ticket: u32,
+    pub token_id: [u8; 32],
+    pub recipient: Pubkey,
+    pub source_chain_id: u64,
+}
+
+#[event]
+pub struct InboundFundsRoutedEvent {
+    pub token_id: [u8; 32],
+    pub destination: Pubkey,
+    pub to_treasury: bool,
+    pub amount: u64,
+    pub timestamp: i64,
+}
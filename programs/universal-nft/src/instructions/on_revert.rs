@@ -1,6 +1,12 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::associated_token::{get_associated_token_address, AssociatedToken};
+use anchor_spl::token::{Mint, Token};
 use crate::state::*;
 use crate::errors::*;
+use crate::gateway::gateway_pda_address;
+use crate::message::{decode_cross_chain_message, decode_revert_context, MESSAGE_MAGIC};
+use crate::nft::{self, NftCustody, RestoreNftAccounts};
 
 /// Called by ZetaChain gateway when a cross-chain call reverts
 #[derive(Accounts)]
@@ -12,11 +18,84 @@ pub struct OnRevert<'info> {
     )]
     pub program_state: Account<'info, ProgramState>,
     
-    /// CHECK: Gateway PDA account
-    #[account(mut)]
+    /// Program PDA credited by the gateway with the SOL returned on revert
+    #[account(
+        mut,
+        seeds = [b"connected"],
+        bump
+    )]
+    pub connected: SystemAccount<'info>,
+    
+    /// CHECK: Gateway PDA, must sign the call, see `gateway::verify_gateway_caller`.
+    /// Owned by the gateway program, so refunds never debit it.
+    #[account(
+        address = gateway_pda_address(&program_state.gateway) @ UniversalNftError::InvalidGatewayPda
+    )]
     pub gateway_pda: UncheckedAccount<'info>,
     
+    /// Transfer record of the operation being reverted, closed into the
+    /// revert address so the token can be transferred again
+    #[account(
+        mut,
+        seeds = [b"transfer", transfer_record.token_id.as_ref()],
+        bump = transfer_record.bump,
+        close = revert_address,
+        constraint = transfer_record.status == TransferStatus::InProgress @ UniversalNftError::TransferNotInProgress
+    )]
+    pub transfer_record: Account<'info, CrossChainTransfer>,
+    
+    /// CHECK: Refund destination, must match the revert address stored on the transfer record
+    #[account(
+        mut,
+        address = transfer_record.revert_address @ UniversalNftError::InvalidRevertAddress
+    )]
+    pub revert_address: UncheckedAccount<'info>,
+    
+    /// Mint of the reverted NFT, which gets its token back
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [b"nft_origin", mint.key().as_ref()],
+        bump = nft_origin.bump,
+        constraint = nft_origin.token_id == transfer_record.token_id @ UniversalNftError::TransferRecordMismatch
+    )]
+    pub nft_origin: Account<'info, NftOrigin>,
+    
+    /// CHECK: Owner the NFT is restored to, must be the sender on the transfer record
+    #[account(address = transfer_record.sender @ UniversalNftError::TransferRecordMismatch)]
+    pub original_owner: UncheckedAccount<'info>,
+    
+    /// CHECK: Original owner's associated token account, created if it was closed,
+    /// or holding the frozen NFT if it is Solana-native
+    #[account(
+        mut,
+        address = get_associated_token_address(&original_owner.key(), &mint.key()) @ UniversalNftError::AccountValidationFailed
+    )]
+    pub owner_token_account: UncheckedAccount<'info>,
+    
+    /// CHECK: Mint authority PDA, delegate of the frozen Solana-native NFTs
+    #[account(
+        mut,
+        seeds = [b"mint_authority"],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: Master edition of a Solana-native NFT, checked by `nft::thaw_nft`
+    pub master_edition: Option<UncheckedAccount<'info>>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
+    /// CHECK: Metaplex token metadata program, needed for Solana-native NFTs
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Instruction sysvar account for caller verification
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+    pub instruction_sysvar_account: UncheckedAccount<'info>,
 }
 
 pub fn on_revert(
@@ -30,14 +109,23 @@ pub fn on_revert(
     msg!("Sender: {}", sender);
     msg!("Data length: {} bytes", data.len());
     
-    let program_state = &mut ctx.accounts.program_state;
-    
     // Parse revert data
     let revert_info = parse_revert_data(&data)?;
     msg!("Revert reason: {}", revert_info.reason);
     msg!("Original token ID: {:?}", revert_info.token_id);
     msg!("Failed operation: {:?}", revert_info.operation_type);
     
+    // The transfer record must belong to the token being reverted
+    require!(
+        ctx.accounts.transfer_record.token_id == revert_info.token_id,
+        UniversalNftError::TransferRecordMismatch
+    );
+    revert_info.check_accounts(
+        &ctx.accounts.transfer_record.key(),
+        &ctx.accounts.transfer_record,
+        &ctx.accounts.mint.key(),
+    )?;
+    
     // Handle the revert based on operation type
     match revert_info.operation_type {
         CrossChainMessageType::Transfer => {
@@ -54,7 +142,8 @@ pub fn on_revert(
         }
     }
     
-    // Check if the revert message contains "revert" and return an error if so (for testing)
+    // Debugging hook that fails the revert handler (test-hooks builds only)
+    #[cfg(feature = "test-hooks")]
     if revert_info.reason.contains("revert") {
         msg!("Revert message detected in revert handler: '{}'", revert_info.reason);
         return Err(UniversalNftError::RevertMessage.into());
@@ -67,74 +156,91 @@ pub fn on_revert(
 }
 
 #[derive(Debug)]
-struct RevertInfo {
+pub(crate) struct RevertInfo {
     pub reason: String,
     pub token_id: [u8; 32],
     pub operation_type: CrossChainMessageType,
     pub original_sender: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub transfer_record: Option<Pubkey>,
+    pub metadata_hash: Option<[u8; 32]>,
     pub timestamp: i64,
 }
 
-fn parse_revert_data(data: &[u8]) -> Result<RevertInfo> {
+impl RevertInfo {
+    /// Checks the accounts against what the revert data names, where it names them
+    pub fn check_accounts(
+        &self,
+        transfer_record_key: &Pubkey,
+        transfer_record: &CrossChainTransfer,
+        mint: &Pubkey,
+    ) -> Result<()> {
+        if self.original_sender != Pubkey::default() {
+            require_keys_eq!(
+                self.original_sender,
+                transfer_record.sender,
+                UniversalNftError::TransferRecordMismatch
+            );
+        }
+        if let Some(context_mint) = self.mint {
+            require_keys_eq!(context_mint, *mint, UniversalNftError::TransferRecordMismatch);
+        }
+        if let Some(context_transfer_record) = self.transfer_record {
+            require_keys_eq!(
+                context_transfer_record,
+                *transfer_record_key,
+                UniversalNftError::TransferRecordMismatch
+            );
+        }
+        // The context must describe the NFT as it was sent
+        if let Some(context_metadata_hash) = self.metadata_hash {
+            require!(
+                context_metadata_hash == transfer_record.metadata_hash,
+                UniversalNftError::MetadataHashMismatch
+            );
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn parse_revert_data(data: &[u8]) -> Result<RevertInfo> {
     msg!("Parsing revert data from {} bytes", data.len());
     
-    if data.is_empty() {
-        msg!("Empty revert data, creating default revert info");
-        return Ok(RevertInfo {
-            reason: "Unknown revert reason".to_string(),
-            token_id: [0u8; 32],
-            operation_type: CrossChainMessageType::Transfer,
-            original_sender: Pubkey::default(),
-            timestamp: Clock::get()?.unix_timestamp,
-        });
-    }
+    require!(!data.is_empty(), UniversalNftError::MessageTruncated);
     
-    // Try to parse as structured data first
-    if let Ok(message) = CrossChainMessage::try_from_slice(data) {
+    // Enveloped cross-chain message
+    if data.starts_with(&MESSAGE_MAGIC) {
+        let message = decode_cross_chain_message(data)?;
         msg!("Parsed structured revert message");
+        
         return Ok(RevertInfo {
             reason: "Structured revert".to_string(),
             token_id: message.token_id,
             operation_type: message.message_type,
-            original_sender: Pubkey::default(), // Would need to be included in message
+            original_sender: Pubkey::default(),
+            mint: None,
+            transfer_record: None,
+            metadata_hash: None,
             timestamp: message.timestamp,
         });
     }
     
-    // Parse as string data
-    let reason = String::from_utf8(data.to_vec())
-        .map_err(|_| UniversalNftError::InvalidDataFormat)?;
-    
-    msg!("Parsed revert reason: {}", reason);
+    // Otherwise the revert data must be the context written by transfer_cross_chain
+    let context = decode_revert_context(data)?;
+    msg!("Parsed revert context version {}", context.version);
     
     Ok(RevertInfo {
-        reason,
-        token_id: generate_token_id_from_revert_data(data),
+        reason: "Transfer reverted".to_string(),
+        token_id: context.token_id,
         operation_type: CrossChainMessageType::Transfer,
-        original_sender: Pubkey::default(),
+        original_sender: context.original_owner,
+        mint: Some(context.mint),
+        transfer_record: Some(context.transfer_record),
+        metadata_hash: Some(context.metadata_hash),
         timestamp: Clock::get()?.unix_timestamp,
     })
 }
 
-fn generate_token_id_from_revert_data(data: &[u8]) -> [u8; 32] {
-    let mut token_id = [0u8; 32];
-    let clock = Clock::get().unwrap();
-    
-    // Create a unique token ID from revert data
-    let data_len = std::cmp::min(data.len(), 16);
-    if data_len > 0 {
-        token_id[0..data_len].copy_from_slice(&data[0..data_len]);
-    }
-    
-    let timestamp_bytes = clock.unix_timestamp.to_le_bytes();
-    token_id[16..24].copy_from_slice(&timestamp_bytes);
-    
-    let slot_bytes = clock.slot.to_le_bytes();
-    token_id[24..32].copy_from_slice(&slot_bytes);
-    
-    token_id
-}
-
 fn handle_transfer_revert(
     ctx: &mut Context<OnRevert>,
     revert_info: &RevertInfo,
@@ -143,30 +249,25 @@ fn handle_transfer_revert(
     msg!("=== HANDLING TRANSFER REVERT ===");
     msg!("Token ID: {:?}", revert_info.token_id);
     msg!("Revert reason: {}", revert_info.reason);
-    msg!("Original sender: {}", revert_info.original_sender);
-    
-    let program_state = &mut ctx.accounts.program_state;
-    
-    // CRITICAL: In a full implementation, this would:
-    // 1. Find the cross-chain transfer record by token ID
-    // 2. Verify the transfer was indeed pending/failed
-    // 3. Re-mint the NFT on Solana to restore it to original owner
-    // 4. Update transfer record status to "Reverted"
-    // 5. Handle any fee refunds
+    msg!("Original sender: {}", ctx.accounts.original_owner.key());
     
     msg!("🔄 REVERT PROCESS:");
-    msg!("  1. Looking up failed transfer for token: {:?}", revert_info.token_id);
-    msg!("  2. Would re-mint NFT to original owner: {}", revert_info.original_sender);
-    msg!("  3. Would mark transfer status as 'Reverted'");
-    msg!("  4. Would refund {} lamports to sender", amount);
+    msg!("  1. Found failed transfer for token: {:?}", revert_info.token_id);
+    msg!("  2. Restoring NFT to original owner: {}", ctx.accounts.original_owner.key());
+    let spent = restore_nft(ctx)?;
+    
+    let refund = amount.saturating_sub(spent);
+    msg!("  3. Refunding {} lamports to revert address", refund);
+    refund_to_revert_address(ctx, revert_info, refund)?;
     
     // Update program statistics
+    let program_state = &mut ctx.accounts.program_state;
     program_state.total_receives += 1; // Count reverts as receives for tracking
     
     // Log the revert details for monitoring
     msg!("✅ Transfer revert processed:");
     msg!("  - Token restored to original chain (Solana)");
-    msg!("  - Original owner: {}", revert_info.original_sender);
+    msg!("  - Original owner: {}", ctx.accounts.original_owner.key());
     msg!("  - Revert reason: {}", revert_info.reason);
     
     // Emit comprehensive revert event
@@ -174,7 +275,7 @@ fn handle_transfer_revert(
         token_id: revert_info.token_id,
         operation_type: revert_info.operation_type.clone(),
         reason: revert_info.reason.clone(),
-        original_sender: revert_info.original_sender,
+        original_sender: ctx.accounts.original_owner.key(),
         amount,
         timestamp: revert_info.timestamp,
     });
@@ -191,19 +292,16 @@ fn handle_mint_revert(
     msg!("Handling mint revert for token ID: {:?}", revert_info.token_id);
     msg!("Revert reason: {}", revert_info.reason);
     
-    // In a full implementation, this would:
-    // 1. Find the failed mint operation
-    // 2. Clean up any partially created accounts
-    // 3. Refund the mint fees
-    // 4. Update operation status
+    // The NFT left Solana with the operation, so it goes back first
+    msg!("Restoring NFT to original owner: {}", ctx.accounts.original_owner.key());
+    let spent = restore_nft(ctx)?;
     
-    let program_state = &mut ctx.accounts.program_state;
-    
-    // For now, just log and update counters
-    msg!("Would clean up failed mint operation");
-    msg!("Would refund {} lamports", amount);
+    let refund = amount.saturating_sub(spent);
+    msg!("Refunding {} lamports to revert address", refund);
+    refund_to_revert_address(ctx, revert_info, refund)?;
     
     // Update program statistics
+    let program_state = &mut ctx.accounts.program_state;
     program_state.total_receives += 1;
     
     // Emit revert event
@@ -211,7 +309,7 @@ fn handle_mint_revert(
         token_id: revert_info.token_id,
         operation_type: revert_info.operation_type.clone(),
         reason: revert_info.reason.clone(),
-        original_sender: revert_info.original_sender,
+        original_sender: ctx.accounts.original_owner.key(),
         amount,
         timestamp: revert_info.timestamp,
     });
@@ -219,6 +317,102 @@ fn handle_mint_revert(
     Ok(())
 }
 
+/// Gives the NFT back to its original owner. Returns the lamports spent
+/// from the connected PDA on recreating a closed token account.
+fn restore_nft(ctx: &mut Context<OnRevert>) -> Result<u64> {
+    let connected_lamports_before = ctx.accounts.connected.lamports();
+    let connected_seeds: &[&[u8]] = &[b"connected", &[ctx.bumps.connected]];
+    
+    nft::restore_nft(&RestoreNftAccounts {
+        payer: ctx.accounts.connected.to_account_info(),
+        payer_seeds: Some(connected_seeds),
+        mint: ctx.accounts.mint.to_account_info(),
+        owner: ctx.accounts.original_owner.to_account_info(),
+        owner_token_account: ctx.accounts.owner_token_account.to_account_info(),
+        mint_authority: ctx.accounts.mint_authority.to_account_info(),
+        mint_authority_bump: ctx.bumps.mint_authority,
+        token_program: ctx.accounts.token_program.to_account_info(),
+        associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        master_edition: ctx.accounts.master_edition.as_ref().map(|account| account.to_account_info()),
+        token_metadata_program: ctx
+            .accounts
+            .token_metadata_program
+            .as_ref()
+            .map(|account| account.to_account_info()),
+    }, NftCustody::of(&ctx.accounts.nft_origin))?;
+    
+    let nft_origin = &mut ctx.accounts.nft_origin;
+    nft_origin.current_chain_id = SOLANA_CHAIN_ID;
+    nft_origin.last_transfer_timestamp = Clock::get()?.unix_timestamp;
+    
+    Ok(connected_lamports_before.saturating_sub(ctx.accounts.connected.lamports()))
+}
+
+/// Returns the deposit carried by a reverted operation to the revert address
+/// recorded on its transfer record and marks the transfer as reverted
+fn refund_to_revert_address(
+    ctx: &mut Context<OnRevert>,
+    revert_info: &RevertInfo,
+    amount: u64,
+) -> Result<()> {
+    let revert_address = ctx.accounts.revert_address.to_account_info();
+    
+    if amount > 0 {
+        refund_from_connected(
+            &ctx.accounts.connected,
+            ctx.bumps.connected,
+            &revert_address,
+            &ctx.accounts.system_program,
+            amount,
+        )?;
+        
+        emit!(RevertRefundEvent {
+            token_id: revert_info.token_id,
+            revert_address: revert_address.key(),
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+    
+    let transfer_record = &mut ctx.accounts.transfer_record;
+    transfer_record.status = TransferStatus::Reverted;
+    transfer_record.completed_at = Some(Clock::get()?.unix_timestamp);
+    
+    Ok(())
+}
+
+/// Pays `amount` lamports from the connected PDA, where the gateway credits
+/// the SOL returned with reverts and aborts, to `revert_address`
+pub(crate) fn refund_from_connected<'info>(
+    connected: &SystemAccount<'info>,
+    connected_bump: u8,
+    revert_address: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        connected.lamports() >= amount,
+        UniversalNftError::InsufficientRefundBalance
+    );
+    
+    let connected_seeds: &[&[u8]] = &[b"connected", &[connected_bump]];
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            Transfer {
+                from: connected.to_account_info(),
+                to: revert_address.clone(),
+            },
+            &[connected_seeds],
+        ),
+        amount,
+    )?;
+    
+    msg!("Refunded {} lamports to {}", amount, revert_address.key());
+    Ok(())
+}
+
 #[event]
 pub struct CrossChainRevertEvent {
     pub token_id: [u8; 32],
@@ -227,4 +421,12 @@ pub struct CrossChainRevertEvent {
     pub original_sender: Pubkey,
     pub amount: u64,
     pub timestamp: i64,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct RevertRefundEvent {
+    pub token_id: [u8; 32],
+    pub revert_address: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
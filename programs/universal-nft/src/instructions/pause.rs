@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = program_state.can_pause(&signer.key()) @ UniversalNftError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    /// Authority or pauser
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetPauser<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNftError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Sets the `flags` pause bits, leaving the others unchanged
+pub fn pause(ctx: Context<SetPaused>, flags: u8) -> Result<()> {
+    let paused = ctx.accounts.program_state.paused | check_flags(flags)?;
+    update_paused(ctx, paused)
+}
+
+/// Clears the `flags` pause bits, leaving the others unchanged
+pub fn unpause(ctx: Context<SetPaused>, flags: u8) -> Result<()> {
+    let paused = ctx.accounts.program_state.paused & !check_flags(flags)?;
+    update_paused(ctx, paused)
+}
+
+/// Queues `pauser` to be designated once the gateway update delay has
+/// elapsed. `Pubkey::default()` leaves pausing to the authority.
+pub fn queue_pauser_update(ctx: Context<SetPauser>, pauser: Pubkey) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+
+    let eta = program_state.queue_pauser(pauser, Clock::get()?.unix_timestamp)?;
+
+    msg!("Pauser update queued");
+    msg!("Current pauser: {}", program_state.pauser);
+    msg!("Queued pauser: {}", pauser);
+    msg!("ETA: {}", eta);
+
+    emit!(PauserUpdateQueuedEvent {
+        current_pauser: program_state.pauser,
+        new_pauser: pauser,
+        eta,
+        authority: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
+
+/// Applies the queued pauser once its ETA has passed
+pub fn execute_pauser_update(ctx: Context<SetPauser>) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+
+    let old_pauser = program_state.pauser;
+    program_state.execute_queued_pauser_update(Clock::get()?.unix_timestamp)?;
+
+    msg!("Pauser updated");
+    msg!("Old pauser: {}", old_pauser);
+    msg!("New pauser: {}", program_state.pauser);
+
+    emit!(PauserUpdatedEvent {
+        old_pauser,
+        new_pauser: program_state.pauser,
+        authority: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
+
+/// Drops the queued pauser. Only the authority can, so a pauser cannot
+/// block its own replacement.
+pub fn cancel_pauser_update(ctx: Context<SetPauser>) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+
+    let cancelled_pauser = program_state.pending_pauser;
+    program_state.cancel_queued_pauser_update()?;
+
+    msg!("Pauser update cancelled");
+    msg!("Cancelled pauser: {}", cancelled_pauser);
+
+    emit!(PauserUpdateCancelledEvent {
+        cancelled_pauser,
+        authority: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
+
+fn check_flags(flags: u8) -> Result<u8> {
+    require!(
+        flags != 0 && flags & !PAUSE_ALL == 0,
+        UniversalNftError::InvalidPauseFlags
+    );
+    Ok(flags)
+}
+
+fn update_paused(ctx: Context<SetPaused>, paused: u8) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+
+    let old_paused = program_state.paused;
+    program_state.paused = paused;
+
+    msg!("Pause state updated");
+    msg!("Old paused bits: {:#05b}", old_paused);
+    msg!("New paused bits: {:#05b}", paused);
+
+    emit!(PauseUpdatedEvent {
+        old_paused,
+        new_paused: paused,
+        signer: ctx.accounts.signer.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PauseUpdatedEvent {
+    pub old_paused: u8,
+    pub new_paused: u8,
+    pub signer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PauserUpdateQueuedEvent {
+    pub current_pauser: Pubkey,
+    pub new_pauser: Pubkey,
+    pub eta: i64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct PauserUpdatedEvent {
+    pub old_pauser: Pubkey,
+    pub new_pauser: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct PauserUpdateCancelledEvent {
+    pub cancelled_pauser: Pubkey,
+    pub authority: Pubkey,
+}
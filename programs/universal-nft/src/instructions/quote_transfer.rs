@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(destination_chain_id: u64)]
+pub struct QuoteTransfer<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// CHECK: Config PDA of the destination chain, which may not exist yet
+    #[account(
+        seeds = [b"chain_config", destination_chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub chain_config: UncheckedAccount<'info>,
+}
+
+/// Cost of a transfer to one chain, returned by `quote_transfer`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TransferQuote {
+    pub destination_chain_id: u64,
+    /// Whether the chain is configured and enabled
+    pub enabled: bool,
+    /// Whether outbound transfers are paused
+    pub paused: bool,
+    /// Protocol fee paid into the treasury
+    pub protocol_fee: u64,
+    /// Lamports deposited with the gateway for destination gas
+    pub gas_deposit: u64,
+    /// Lamports the owner pays on top of rent for the transfer record
+    pub total: u64,
+}
+
+/// Quotes a transfer to `destination_chain_id`; an unconfigured chain is
+/// quoted as disabled and free.
+pub fn transfer_quote(
+    destination_chain_id: u64,
+    chain_config: Option<&ChainConfig>,
+    paused: u8,
+) -> Result<TransferQuote> {
+    let (enabled, protocol_fee, gas_deposit) = match chain_config {
+        Some(config) => (config.enabled, config.transfer_fee()?, config.gas_deposit),
+        None => (false, 0, 0),
+    };
+    let total = protocol_fee
+        .checked_add(gas_deposit)
+        .ok_or(crate::errors::UniversalNftError::ArithmeticOverflow)?;
+    
+    Ok(TransferQuote {
+        destination_chain_id,
+        enabled,
+        paused: paused & PAUSE_OUTBOUND != 0,
+        protocol_fee,
+        gas_deposit,
+        total,
+    })
+}
+
+/// Read-only: the quote is returned as instruction return data, for clients
+/// to read through transaction simulation
+pub fn quote_transfer(ctx: Context<QuoteTransfer>, destination_chain_id: u64) -> Result<TransferQuote> {
+    let chain_config_info = ctx.accounts.chain_config.to_account_info();
+    let chain_config = if chain_config_info.owner == ctx.program_id && !chain_config_info.data_is_empty() {
+        Some(ChainConfig::try_deserialize(&mut &chain_config_info.try_borrow_data()?[..])?)
+    } else {
+        None
+    };
+    
+    let quote = transfer_quote(
+        destination_chain_id,
+        chain_config.as_ref(),
+        ctx.accounts.program_state.paused,
+    )?;
+    
+    msg!("Transfer quote for chain {}", destination_chain_id);
+    msg!("Enabled: {}, paused: {}", quote.enabled, quote.paused);
+    msg!("Protocol fee: {} lamports", quote.protocol_fee);
+    msg!("Gas deposit: {} lamports", quote.gas_deposit);
+    msg!("Total: {} lamports", quote.total);
+    
+    Ok(quote)
+}
@@ -1,11 +1,15 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo};
-use mpl_token_metadata::types::{DataV2, Creator};
-use mpl_token_metadata::instructions::CreateMetadataAccountV3;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::Token;
+use mpl_token_metadata::types::Creator;
 use crate::state::*;
+use crate::errors::UniversalNftError;
+use crate::gateway::gateway_pda_address;
+use crate::nft::{issue_inbound_nft, InboundNftAccounts};
 
 
 #[derive(Accounts)]
+#[instruction(token_id: [u8; 32])]
 pub struct ReceiveCrossChain<'info> {
     #[account(
         mut,
@@ -14,68 +18,72 @@ pub struct ReceiveCrossChain<'info> {
     )]
     pub program_state: Account<'info, ProgramState>,
     
-    #[account(
-        init,
-        payer = payer,
-        mint::decimals = 0,
-        mint::authority = mint_authority,
-        mint::freeze_authority = mint_authority,
-    )]
-    pub mint: Account<'info, Mint>,
+    /// CHECK: Mint PDA of the token ID, created on first arrival and reused
+    /// when the NFT returns to Solana, checked by `nft::issue_inbound_nft`
+    #[account(mut)]
+    pub mint: UncheckedAccount<'info>,
     
-    #[account(
-        init,
-        payer = payer,
-        associated_token::mint = mint,
-        associated_token::authority = recipient,
-    )]
-    pub token_account: Account<'info, TokenAccount>,
+    /// CHECK: Recipient's associated token account, created if missing
+    #[account(mut)]
+    pub token_account: UncheckedAccount<'info>,
     
-    #[account(
-        init,
-        payer = payer,
-        space = NftOrigin::LEN,
-        seeds = [b"nft_origin", mint.key().as_ref()],
-        bump
-    )]
-    pub nft_origin: Account<'info, NftOrigin>,
+    /// CHECK: Origin record PDA of the mint, created on first arrival
+    #[account(mut)]
+    pub nft_origin: UncheckedAccount<'info>,
     
     /// CHECK: Metadata account will be created by Metaplex
     #[account(mut)]
-    pub metadata: AccountInfo<'info>,
+    pub metadata: UncheckedAccount<'info>,
     
-    /// CHECK: Mint authority PDA
+    /// CHECK: Mint authority PDA, delegate of the frozen Solana-native NFTs
     #[account(
+        mut,
         seeds = [b"mint_authority"],
         bump
     )]
-    pub mint_authority: AccountInfo<'info>,
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: Master edition of a returning Solana-native NFT, checked by
+    /// `nft::thaw_nft`
+    pub master_edition: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Token account a returning Solana-native NFT was frozen in,
+    /// checked by `nft::thaw_nft`
+    #[account(mut)]
+    pub locked_token_account: Option<UncheckedAccount<'info>>,
     
     #[account(mut)]
     pub payer: Signer<'info>,
     
     /// CHECK: NFT recipient
-    pub recipient: AccountInfo<'info>,
+    pub recipient: UncheckedAccount<'info>,
     
-    /// CHECK: ZetaChain gateway program - only gateway can call this
+    /// CHECK: Gateway PDA, must sign the call, see `gateway::verify_gateway_caller`
     #[account(
-        address = program_state.gateway,
-        signer
+        address = gateway_pda_address(&program_state.gateway) @ UniversalNftError::InvalidGatewayPda
     )]
-    pub gateway: Signer<'info>,
+    pub gateway_pda: UncheckedAccount<'info>,
     
     pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
     /// CHECK: Metaplex token metadata program
     #[account(address = mpl_token_metadata::ID)]
-    pub token_metadata_program: AccountInfo<'info>,
+    pub token_metadata_program: UncheckedAccount<'info>,
+    
+    /// CHECK: Instruction sysvar account for caller verification
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::id())]
+    pub instruction_sysvar_account: UncheckedAccount<'info>,
 }
 
+/// Issues an NFT arriving from `origin_chain_id` the same way `on_call` does:
+/// a new mint on first arrival, a re-mint for a returning burned NFT, and a
+/// thaw for a returning Solana-native one
 pub fn receive_cross_chain(
     ctx: Context<ReceiveCrossChain>,
     token_id: [u8; 32],
+    origin_chain_id: u64,
     name: String,
     symbol: String,
     uri: String,
@@ -83,166 +91,104 @@ pub fn receive_cross_chain(
 ) -> Result<()> {
     msg!("=== CROSS-CHAIN RECEIVE START ===");
     msg!("Token ID: {:?}", token_id);
+    msg!("Origin chain: {}", origin_chain_id);
     msg!("Name: {}", name);
     msg!("Symbol: {}", symbol);
     msg!("URI: {}", uri);
-    msg!("New Mint: {}", ctx.accounts.mint.key());
+    msg!("Mint: {}", ctx.accounts.mint.key());
     msg!("Recipient: {}", ctx.accounts.recipient.key());
-    msg!("Gateway: {}", ctx.accounts.gateway.key());
+    msg!("Gateway PDA: {}", ctx.accounts.gateway_pda.key());
     msg!("Payer: {}", ctx.accounts.payer.key());
-
-    // Validate inputs
-    require!(!name.is_empty(), crate::errors::UniversalNftError::InvalidInstructionData);
-    require!(!symbol.is_empty(), crate::errors::UniversalNftError::InvalidInstructionData);
-    require!(token_id != [0u8; 32], crate::errors::UniversalNftError::InvalidTokenId);
-
-    let program_state = &mut ctx.accounts.program_state;
-    let mint = &ctx.accounts.mint;
-    let token_account = &ctx.accounts.token_account;
-    let nft_origin = &mut ctx.accounts.nft_origin;
     
-    // Check if this NFT has been on Solana before by looking at the origin account
-    let is_returning_nft = nft_origin.original_mint != Pubkey::default();
-    
-    if is_returning_nft {
-        msg!("✅ NFT returning to Solana");
-        msg!("  Original mint: {}", nft_origin.original_mint);
-        msg!("  Origin chain: {}", nft_origin.origin_chain_id);
-        msg!("  Previous transfer count: {}", nft_origin.transfer_count);
-        msg!("  Last transfer: {}", nft_origin.last_transfer_timestamp);
-        
-        // Update the existing origin record
-        nft_origin.current_chain_id = SOLANA_CHAIN_ID;
-        nft_origin.transfer_count += 1;
-        nft_origin.last_transfer_timestamp = Clock::get()?.unix_timestamp;
-    } else {
-        msg!("✅ NFT first time on Solana from external chain");
-        
-        // Initialize new origin information
-        nft_origin.token_id = token_id;
-        nft_origin.origin_chain_id = 1; // External chain ID (would come from cross-chain message)
-        nft_origin.current_chain_id = SOLANA_CHAIN_ID;
-        nft_origin.block_number = Clock::get()?.slot;
-        nft_origin.transfer_count = 1;
-        nft_origin.last_transfer_timestamp = Clock::get()?.unix_timestamp;
-        nft_origin.bump = ctx.bumps.nft_origin;
-    }
+    ctx.accounts.program_state.require_not_paused(PAUSE_INBOUND)?;
     
-    // Always update the current mint reference
-    nft_origin.original_mint = mint.key();
+    // Validate inputs
+    require!(!name.is_empty(), UniversalNftError::InvalidInstructionData);
+    require!(!symbol.is_empty(), UniversalNftError::InvalidInstructionData);
+    require!(token_id != [0u8; 32], UniversalNftError::InvalidTokenId);
+    require!(origin_chain_id > 0, UniversalNftError::InvalidChainId);
     
-    msg!("NFT origin updated:");
-    msg!("  Token ID: {:?}", nft_origin.token_id);
-    msg!("  Origin chain: {}", nft_origin.origin_chain_id);
-    msg!("  Current chain: {}", nft_origin.current_chain_id);
-    msg!("  Transfer count: {}", nft_origin.transfer_count);
-
     // Validate creators if provided
     if let Some(ref creators_vec) = creators {
         msg!("Validating {} creators", creators_vec.len());
         let mut total_share = 0u8;
         for (i, creator) in creators_vec.iter().enumerate() {
-            msg!("Creator {}: address={}, verified={}, share={}%", 
+            msg!("Creator {}: address={}, verified={}, share={}%",
                 i, creator.address, creator.verified, creator.share);
-            total_share += creator.share;
+            total_share = total_share
+                .checked_add(creator.share)
+                .ok_or(UniversalNftError::InvalidInstructionData)?;
         }
-        require!(total_share <= 100, crate::errors::UniversalNftError::InvalidInstructionData);
+        require!(total_share <= 100, UniversalNftError::InvalidInstructionData);
     }
     
-    // Create metadata for the new mint
-    let metadata_data = DataV2 {
-        name: name.clone(),
-        symbol: symbol.clone(),
-        uri: uri.clone(),
-        seller_fee_basis_points: 0,
-        creators,
-        collection: None,
-        uses: None,
-    };
-    
-    // Create metadata account
-    let create_metadata_ix = CreateMetadataAccountV3 {
-        metadata: ctx.accounts.metadata.key(),
-        mint: mint.key(),
-        mint_authority: ctx.accounts.mint_authority.key(),
-        payer: ctx.accounts.payer.key(),
-        update_authority: (ctx.accounts.mint_authority.key(), true),
-        system_program: ctx.accounts.system_program.key(),
-        rent: Some(ctx.accounts.rent.key()),
-    };
-    
-    // Prepare signer seeds
-    let mint_authority_bump = ctx.bumps.mint_authority;
-    let mint_authority_seeds: &[&[u8]] = &[b"mint_authority", &[mint_authority_bump]];
-    let signer_seeds = &[mint_authority_seeds];
-    
-    msg!("Creating metadata account...");
-    match anchor_lang::solana_program::program::invoke_signed(
-        &create_metadata_ix.instruction(mpl_token_metadata::instructions::CreateMetadataAccountV3InstructionArgs {
-            data: metadata_data,
-            is_mutable: true,
-            collection_details: None,
-        }),
-        &[
-            ctx.accounts.metadata.to_account_info(),
-            ctx.accounts.mint.to_account_info(),
-            ctx.accounts.mint_authority.to_account_info(),
-            ctx.accounts.payer.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-            ctx.accounts.rent.to_account_info(),
-        ],
-        signer_seeds,
-    ) {
-        Ok(_) => msg!("✅ Metadata account created successfully"),
-        Err(e) => {
-            msg!("❌ Metadata creation failed: {:?}", e);
-            return Err(crate::errors::UniversalNftError::MetadataCreationFailed.into());
-        }
-    }
-    
-    // Mint token to recipient
-    msg!("Minting token to recipient...");
-    let mint_to_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        MintTo {
-            mint: ctx.accounts.mint.to_account_info(),
-            to: token_account.to_account_info(),
-            authority: ctx.accounts.mint_authority.to_account_info(),
+    let message = CrossChainMessage {
+        message_type: CrossChainMessageType::Transfer,
+        token_id,
+        source_chain_id: ZETACHAIN_CHAIN_ID,
+        destination_chain_id: SOLANA_CHAIN_ID,
+        sender: [0u8; 20],
+        recipient: ctx.accounts.recipient.key().to_bytes(),
+        metadata: NftMetadata {
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points: 0,
+            creators: creators.map(|creators| {
+                creators
+                    .iter()
+                    .map(|creator| NftCreator {
+                        address: creator.address.to_bytes(),
+                        verified: creator.verified,
+                        share: creator.share,
+                    })
+                    .collect()
+            }),
+            collection: None,
         },
-        signer_seeds,
-    );
+        timestamp: Clock::get()?.unix_timestamp,
+        origin_chain_id,
+    };
     
-    match token::mint_to(mint_to_ctx, 1) {
-        Ok(_) => msg!("✅ Token minted successfully"),
-        Err(e) => {
-            msg!("❌ Token mint failed: {:?}", e);
-            return Err(crate::errors::UniversalNftError::TokenMintFailed.into());
-        }
-    }
+    let accounts = &ctx.accounts;
+    let is_returning_nft = issue_inbound_nft(&InboundNftAccounts {
+        program_id: ctx.program_id,
+        payer: accounts.payer.to_account_info(),
+        payer_seeds: None,
+        mint: accounts.mint.to_account_info(),
+        token_account: accounts.token_account.to_account_info(),
+        nft_origin: accounts.nft_origin.to_account_info(),
+        metadata: accounts.metadata.to_account_info(),
+        mint_authority: accounts.mint_authority.to_account_info(),
+        mint_authority_bump: ctx.bumps.mint_authority,
+        recipient: accounts.recipient.to_account_info(),
+        token_program: accounts.token_program.to_account_info(),
+        associated_token_program: accounts.associated_token_program.to_account_info(),
+        system_program: accounts.system_program.to_account_info(),
+        rent: accounts.rent.to_account_info(),
+        token_metadata_program: accounts.token_metadata_program.to_account_info(),
+        master_edition: accounts.master_edition.as_ref().map(|account| account.to_account_info()),
+        locked_token_account: accounts
+            .locked_token_account
+            .as_ref()
+            .map(|account| account.to_account_info()),
+    }, &message)?;
     
     // Update program state
-    program_state.total_minted += 1;
+    let program_state = &mut ctx.accounts.program_state;
+    if !is_returning_nft {
+        program_state.total_minted += 1;
+    }
     program_state.total_receives += 1;
     
     msg!("✅ NFT received from cross-chain successfully:");
     msg!("  Token ID: {:?}", token_id);
-    msg!("  New Mint: {}", mint.key());
+    msg!("  Mint: {}", ctx.accounts.mint.key());
     msg!("  Recipient: {}", ctx.accounts.recipient.key());
     msg!("  Is returning NFT: {}", is_returning_nft);
     msg!("  Total minted: {}", program_state.total_minted);
     msg!("  Total receives: {}", program_state.total_receives);
     
-    // Emit event for off-chain indexing
-    emit!(CrossChainReceiveEvent {
-        token_id,
-        mint: mint.key(),
-        recipient: ctx.accounts.recipient.key(),
-        is_returning: is_returning_nft,
-        source_chain_id: nft_origin.origin_chain_id,
-        transfer_count: nft_origin.transfer_count,
-        timestamp: Clock::get()?.unix_timestamp,
-    });
-    
     msg!("=== CROSS-CHAIN RECEIVE END ===");
     
     Ok(())
@@ -257,4 +203,4 @@ pub struct CrossChainReceiveEvent {
     pub source_chain_id: u64,
     pub transfer_count: u64,
     pub timestamp: i64,
-}
\ No newline at end of file
+}
@@ -0,0 +1,211 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64)]
+pub struct SetChainConfig<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        seeds = [b"roles"],
+        bump = roles.bump,
+        constraint = roles.authorizes(&program_state.authority, Role::ChainManager, &authority.key()) @ UniversalNftError::Unauthorized
+    )]
+    pub roles: Account<'info, Roles>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ChainConfig::INIT_SPACE,
+        seeds = [b"chain_config", chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+    
+    /// Authority or chain manager, paying for new configs
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_chain_config(
+    ctx: Context<SetChainConfig>,
+    chain_id: u64,
+    codec: MessageCodec,
+    enabled: bool,
+    min_revert_gas_limit: u64,
+    max_revert_gas_limit: u64,
+) -> Result<()> {
+    require!(chain_id > 0, UniversalNftError::InvalidChainId);
+    require!(chain_id != SOLANA_CHAIN_ID, UniversalNftError::InvalidChainId);
+    require!(
+        min_revert_gas_limit <= max_revert_gas_limit,
+        UniversalNftError::InvalidInstructionData
+    );
+    
+    let chain_config = &mut ctx.accounts.chain_config;
+    chain_config.chain_id = chain_id;
+    chain_config.codec = codec;
+    chain_config.enabled = enabled;
+    chain_config.min_revert_gas_limit = min_revert_gas_limit;
+    chain_config.max_revert_gas_limit = max_revert_gas_limit;
+    chain_config.bump = ctx.bumps.chain_config;
+    
+    msg!("Chain config updated");
+    msg!("Chain ID: {}", chain_id);
+    msg!("Codec: {:?}", codec);
+    msg!("Enabled: {}", enabled);
+    msg!("Revert gas limit bounds: {}..={}", min_revert_gas_limit, max_revert_gas_limit);
+    
+    emit!(ChainConfigUpdatedEvent {
+        chain_id,
+        codec,
+        enabled,
+        min_revert_gas_limit,
+        max_revert_gas_limit,
+        authority: ctx.accounts.authority.key(),
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct ChainConfigUpdatedEvent {
+    pub chain_id: u64,
+    pub codec: MessageCodec,
+    pub enabled: bool,
+    pub min_revert_gas_limit: u64,
+    pub max_revert_gas_limit: u64,
+    pub authority: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64)]
+pub struct SetChainFees<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        seeds = [b"roles"],
+        bump = roles.bump,
+        constraint = roles.authorizes(&program_state.authority, Role::FeeManager, &fee_manager.key()) @ UniversalNftError::Unauthorized
+    )]
+    pub roles: Account<'info, Roles>,
+    
+    #[account(
+        mut,
+        seeds = [b"chain_config", chain_id.to_le_bytes().as_ref()],
+        bump = chain_config.bump
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+    
+    /// Authority or fee manager
+    pub fee_manager: Signer<'info>,
+}
+
+/// Sets what a transfer to `chain_id` costs on top of rent: the gas deposit
+/// forwarded to the gateway and the protocol fee kept by the treasury
+pub fn set_chain_fees(
+    ctx: Context<SetChainFees>,
+    chain_id: u64,
+    gas_deposit: u64,
+    fee_lamports: u64,
+    fee_bps: u16,
+) -> Result<()> {
+    require!(fee_bps <= MAX_FEE_BPS, UniversalNftError::InvalidFeeBps);
+    
+    let chain_config = &mut ctx.accounts.chain_config;
+    chain_config.gas_deposit = gas_deposit;
+    chain_config.fee_lamports = fee_lamports;
+    chain_config.fee_bps = fee_bps;
+    let fee = chain_config.transfer_fee()?;
+    
+    msg!("Chain fees updated");
+    msg!("Chain ID: {}", chain_id);
+    msg!("Gas deposit: {} lamports", gas_deposit);
+    msg!("Protocol fee: {} lamports + {} bps ({} lamports)", fee_lamports, fee_bps, fee);
+    
+    emit!(ChainFeesUpdatedEvent {
+        chain_id,
+        gas_deposit,
+        fee_lamports,
+        fee_bps,
+        fee_manager: ctx.accounts.fee_manager.key(),
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct ChainFeesUpdatedEvent {
+    pub chain_id: u64,
+    pub gas_deposit: u64,
+    pub fee_lamports: u64,
+    pub fee_bps: u16,
+    pub fee_manager: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64)]
+pub struct SetUniversalContract<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        seeds = [b"roles"],
+        bump = roles.bump,
+        constraint = roles.authorizes(&program_state.authority, Role::ChainManager, &authority.key()) @ UniversalNftError::Unauthorized
+    )]
+    pub roles: Account<'info, Roles>,
+    
+    #[account(
+        mut,
+        seeds = [b"chain_config", chain_id.to_le_bytes().as_ref()],
+        bump = chain_config.bump
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+    
+    /// Authority or chain manager
+    pub authority: Signer<'info>,
+}
+
+/// Records the universal NFT contract deployed on `chain_id`. On ZetaChain,
+/// only calls from this contract may redeem allowlist claims; zero unsets it.
+pub fn set_universal_contract(
+    ctx: Context<SetUniversalContract>,
+    chain_id: u64,
+    universal_contract: [u8; 20],
+) -> Result<()> {
+    ctx.accounts.chain_config.universal_contract = universal_contract;
+    
+    msg!("Universal contract updated");
+    msg!("Chain ID: {}", chain_id);
+    msg!("Universal contract: 0x{}", hex::encode(universal_contract));
+    
+    emit!(UniversalContractUpdatedEvent {
+        chain_id,
+        universal_contract,
+        authority: ctx.accounts.authority.key(),
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct UniversalContractUpdatedEvent {
+    pub chain_id: u64,
+    pub universal_contract: [u8; 20],
+    pub authority: Pubkey,
+}
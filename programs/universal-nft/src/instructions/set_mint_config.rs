@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct SetMintConfig<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNftError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + MintConfig::INIT_SPACE,
+        seeds = [b"mint_config"],
+        bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Replaces the minting terms. The count of NFTs already minted is kept, so
+/// lowering `max_supply` below it closes minting.
+pub fn set_mint_config(ctx: Context<SetMintConfig>, settings: MintSettings) -> Result<()> {
+    settings.validate()?;
+    
+    let mint_config = &mut ctx.accounts.mint_config;
+    mint_config.settings = settings.clone();
+    mint_config.bump = ctx.bumps.mint_config;
+    
+    msg!("Mint config updated");
+    msg!("Price: {} ({:?})", settings.price, settings.payment_mint);
+    msg!("Max supply: {}, minted: {}", settings.max_supply, mint_config.minted);
+    msg!("Window: {}..{}", settings.start_time, settings.end_time);
+    msg!("Wallet limit: {}", settings.wallet_limit);
+    msg!("Proceeds payees: {}", settings.proceeds.len());
+    
+    emit!(MintConfigUpdatedEvent {
+        settings,
+        authority: ctx.accounts.authority.key(),
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct MintConfigUpdatedEvent {
+    pub settings: MintSettings,
+    pub authority: Pubkey,
+}
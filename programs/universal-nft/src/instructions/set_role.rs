@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct SetRole<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNftError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Created here for deployments initialized before roles existed
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + Roles::INIT_SPACE,
+        seeds = [b"roles"],
+        bump
+    )]
+    pub roles: Account<'info, Roles>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Assigns `role` to `holder`. `Pubkey::default()` revokes it.
+pub fn set_role(
+    ctx: Context<SetRole>,
+    role: Role,
+    holder: Pubkey,
+) -> Result<()> {
+    let roles = &mut ctx.accounts.roles;
+    
+    let old_holder = roles.holder(role);
+    roles.set_holder(role, holder);
+    roles.bump = ctx.bumps.roles;
+    
+    msg!("Role updated");
+    msg!("Role: {:?}", role);
+    msg!("Old holder: {}", old_holder);
+    msg!("New holder: {}", holder);
+    
+    emit!(RoleUpdatedEvent {
+        role,
+        old_holder,
+        new_holder: holder,
+        authority: ctx.accounts.authority.key(),
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct RoleUpdatedEvent {
+    pub role: Role,
+    pub old_holder: Pubkey,
+    pub new_holder: Pubkey,
+    pub authority: Pubkey,
+}
@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct SetTssAddress<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNftError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelTssAddressUpdate<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = program_state.can_pause(&signer.key()) @ UniversalNftError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// Authority or pauser, so a watcher can veto a rogue rotation
+    pub signer: Signer<'info>,
+}
+
+/// Queues `tss_address` to replace the TSS address once the gateway update
+/// delay has elapsed. A zero address disables `execute_signed` at once.
+pub fn queue_tss_address_update(
+    ctx: Context<SetTssAddress>,
+    tss_address: [u8; 20],
+) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+    
+    let old_tss_address = program_state.tss_address;
+    match program_state.queue_tss_address(tss_address, Clock::get()?.unix_timestamp)? {
+        None => {
+            msg!("TSS address cleared, signed execution disabled");
+            msg!("Old TSS address: 0x{}", hex::encode(old_tss_address));
+    
+            emit!(TssAddressUpdatedEvent {
+                old_tss_address,
+                new_tss_address: tss_address,
+                authority: ctx.accounts.authority.key(),
+            });
+        }
+        Some(eta) => {
+            msg!("TSS address rotation queued");
+            msg!("Current TSS address: 0x{}", hex::encode(old_tss_address));
+            msg!("Queued TSS address: 0x{}", hex::encode(tss_address));
+            msg!("ETA: {}", eta);
+    
+            emit!(TssAddressUpdateQueuedEvent {
+                current_tss_address: old_tss_address,
+                new_tss_address: tss_address,
+                eta,
+                authority: ctx.accounts.authority.key(),
+            });
+        }
+    }
+    
+    Ok(())
+}
+
+/// Applies the queued TSS address once its ETA has passed
+pub fn execute_tss_address_update(ctx: Context<SetTssAddress>) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+    
+    let old_tss_address = program_state.tss_address;
+    program_state.execute_queued_tss_update(Clock::get()?.unix_timestamp)?;
+    
+    msg!("TSS address updated");
+    msg!("Old TSS address: 0x{}", hex::encode(old_tss_address));
+    msg!("New TSS address: 0x{}", hex::encode(program_state.tss_address));
+    msg!("Next nonce: {}", program_state.tss_nonce);
+    
+    emit!(TssAddressUpdatedEvent {
+        old_tss_address,
+        new_tss_address: program_state.tss_address,
+        authority: ctx.accounts.authority.key(),
+    });
+    
+    Ok(())
+}
+
+/// Drops the queued TSS address
+pub fn cancel_tss_address_update(ctx: Context<CancelTssAddressUpdate>) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+    
+    let cancelled_tss_address = program_state.pending_tss_address;
+    program_state.cancel_queued_tss_update()?;
+    
+    msg!("TSS address rotation cancelled");
+    msg!("Cancelled TSS address: 0x{}", hex::encode(cancelled_tss_address));
+    
+    emit!(TssAddressUpdateCancelledEvent {
+        cancelled_tss_address,
+        signer: ctx.accounts.signer.key(),
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct TssAddressUpdateQueuedEvent {
+    pub current_tss_address: [u8; 20],
+    pub new_tss_address: [u8; 20],
+    pub eta: i64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct TssAddressUpdatedEvent {
+    pub old_tss_address: [u8; 20],
+    pub new_tss_address: [u8; 20],
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct TssAddressUpdateCancelledEvent {
+    pub cancelled_tss_address: [u8; 20],
+    pub signer: Pubkey,
+}
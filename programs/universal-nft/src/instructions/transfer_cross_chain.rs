@@ -1,8 +1,13 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Burn};
+use anchor_spl::token::{self, Approve, Mint, Token, TokenAccount, Burn};
 use anchor_lang::solana_program::program::invoke;
+use anchor_lang::system_program::{self, Transfer};
 use crate::state::*;
 use crate::errors::*;
+use crate::message::{encode_cross_chain_message, encode_revert_context, metadata_hash, REVERT_CONTEXT_VERSION};
+use crate::abi::{encode_universal_nft_payload, UniversalNftPayload};
+use crate::gateway::{deposit_and_call_instruction, gateway_pda_address, DepositAndCallArgs};
+use crate::nft::{freeze_nft, NftCustody, NftLockAccounts};
 
 #[derive(Accounts)]
 #[instruction(destination_chain_id: u64, recipient: [u8; 32])]
@@ -34,31 +39,72 @@ pub struct TransferCrossChain<'info> {
     #[account(
         init,
         payer = owner,
-        space = CrossChainTransfer::LEN,
+        space = 8 + CrossChainTransfer::INIT_SPACE,
         seeds = [b"transfer", nft_origin.token_id.as_ref()],
         bump
     )]
     pub transfer_record: Account<'info, CrossChainTransfer>,
     
+    #[account(
+        seeds = [b"chain_config", destination_chain_id.to_le_bytes().as_ref()],
+        bump = chain_config.bump,
+        constraint = chain_config.enabled @ UniversalNftError::CrossChainNotSupported
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+    
+    /// Receives the protocol fee
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
     #[account(mut)]
     pub owner: Signer<'info>,
     
+    /// EVM address linked to the owner, required as sender and used as the
+    /// default abort address
+    #[account(
+        seeds = [b"address_link", owner.key().as_ref()],
+        bump = address_link.bump
+    )]
+    pub address_link: Option<Account<'info, AddressLink>>,
+    
     /// CHECK: ZetaChain gateway program
     #[account(address = program_state.gateway)]
     pub gateway: AccountInfo<'info>,
     
     /// CHECK: Gateway PDA for cross-chain operations
-    #[account(mut)]
+    #[account(
+        mut,
+        address = gateway_pda_address(&program_state.gateway) @ UniversalNftError::InvalidGatewayPda
+    )]
     pub gateway_pda: UncheckedAccount<'info>,
     
+    /// CHECK: Mint authority PDA, delegate of the frozen Solana-native NFTs
+    #[account(
+        mut,
+        seeds = [b"mint_authority"],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: Master edition of a Solana-native NFT, checked by `nft::freeze_nft`
+    pub master_edition: Option<UncheckedAccount<'info>>,
+    
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
+    /// CHECK: Metaplex token metadata program, needed for Solana-native NFTs
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: Option<UncheckedAccount<'info>>,
 }
 
 pub fn transfer_cross_chain(
     ctx: Context<TransferCrossChain>,
     destination_chain_id: u64,
     recipient: [u8; 32],
+    revert_options: Option<TransferRevertOptions>,
 ) -> Result<()> {
     msg!("=== CROSS-CHAIN TRANSFER START ===");
     msg!("Mint: {}", ctx.accounts.mint.key());
@@ -68,6 +114,8 @@ pub fn transfer_cross_chain(
     msg!("Token Account: {}", ctx.accounts.token_account.key());
     msg!("Gateway: {}", ctx.accounts.gateway.key());
 
+    ctx.accounts.program_state.require_not_paused(PAUSE_OUTBOUND)?;
+
     let program_state = &mut ctx.accounts.program_state;
     let token_account = &ctx.accounts.token_account;
     let nft_origin = &mut ctx.accounts.nft_origin;
@@ -80,19 +128,48 @@ pub fn transfer_cross_chain(
     require!(recipient != [0u8; 32], UniversalNftError::InvalidRecipient);
     
     msg!("✅ Input validation passed");
+    
+    // Send from the EVM address the owner proved control of
+    let evm_sender = linked_evm_sender(ctx.accounts.address_link.as_deref())?;
+    msg!("Using linked EVM address: 0x{}", hex::encode(evm_sender));
     msg!("Current NFT location - Chain: {}, Transfer count: {}", 
         nft_origin.current_chain_id, nft_origin.transfer_count);
 
+    // Use the caller's revert handling, or refund the owner by default
+    let transfer_revert_options = match revert_options {
+        Some(options) => options,
+        None => TransferRevertOptions::for_owner(ctx.accounts.owner.key(), evm_sender),
+    };
+    ctx.accounts.chain_config.check_revert_options(&transfer_revert_options)?;
+
+    // Charge the protocol fee before anything leaves Solana
+    let fee = ctx.accounts.chain_config.transfer_fee()?;
+    if fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            fee,
+        )?;
+        ctx.accounts.treasury.record_collected(fee)?;
+        msg!("Charged protocol fee: {} lamports", fee);
+    }
+
     // Create comprehensive cross-chain message with enhanced metadata
     let cross_chain_message = CrossChainMessage {
         message_type: CrossChainMessageType::Transfer,
         token_id: nft_origin.token_id,
         source_chain_id: SOLANA_CHAIN_ID,
         destination_chain_id,
-        sender: solana_to_evm_address(&ctx.accounts.owner.key()),
+        sender: evm_sender,
         recipient,
         metadata: create_enhanced_transfer_metadata(&nft_origin.token_id, &ctx.accounts.mint.key()),
         timestamp: Clock::get()?.unix_timestamp,
+        origin_chain_id: nft_origin.origin_chain_id,
     };
 
     msg!("Created cross-chain message:");
@@ -107,35 +184,71 @@ pub fn transfer_cross_chain(
     transfer_record.destination_chain_id = destination_chain_id;
     transfer_record.sender = ctx.accounts.owner.key();
     transfer_record.recipient = recipient;
+    transfer_record.revert_address = transfer_revert_options.revert_address;
+    transfer_record.abort_address = transfer_revert_options.abort_address;
+    transfer_record.call_on_revert = transfer_revert_options.call_on_revert;
+    transfer_record.on_revert_gas_limit = transfer_revert_options.on_revert_gas_limit;
     transfer_record.status = TransferStatus::Pending;
     transfer_record.initiated_at = Clock::get()?.unix_timestamp;
     transfer_record.completed_at = None;
     transfer_record.error_message = None;
+    transfer_record.metadata_hash = metadata_hash(&cross_chain_message.metadata)?;
     transfer_record.bump = ctx.bumps.transfer_record;
 
     msg!("Transfer record initialized with status: {:?}", transfer_record.status);
 
-    // Burn the NFT (this removes it from Solana)
-    msg!("Burning NFT on Solana...");
-    let burn_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        Burn {
+    // Solana-native NFTs cannot be minted again, so they are frozen in place
+    if NftCustody::of(nft_origin) == NftCustody::Freeze {
+        msg!("Freezing Solana-native NFT...");
+        let (Some(master_edition), Some(token_metadata_program)) =
+            (&ctx.accounts.master_edition, &ctx.accounts.token_metadata_program)
+        else {
+            return Err(UniversalNftError::NftNotLocked.into());
+        };
+        token::approve(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Approve {
+                    to: token_account.to_account_info(),
+                    delegate: ctx.accounts.mint_authority.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            1,
+        )?;
+        freeze_nft(&NftLockAccounts {
             mint: ctx.accounts.mint.to_account_info(),
-            from: token_account.to_account_info(),
-            authority: ctx.accounts.owner.to_account_info(),
-        },
-    );
+            token_account: token_account.to_account_info(),
+            master_edition: master_edition.to_account_info(),
+            mint_authority: ctx.accounts.mint_authority.to_account_info(),
+            mint_authority_bump: ctx.bumps.mint_authority,
+            token_program: ctx.accounts.token_program.to_account_info(),
+            token_metadata_program: token_metadata_program.to_account_info(),
+        })?;
+        transfer_record.status = TransferStatus::InProgress;
+    } else {
+        // Burn the NFT (this removes it from Solana)
+        msg!("Burning NFT on Solana...");
+        let burn_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: token_account.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        );
     
-    match token::burn(burn_ctx, 1) {
-        Ok(_) => {
-            msg!("✅ NFT burned successfully on Solana");
-            transfer_record.status = TransferStatus::InProgress;
-        },
-        Err(e) => {
-            msg!("❌ NFT burn failed: {:?}", e);
-            transfer_record.status = TransferStatus::Failed;
-            transfer_record.error_message = Some("Burn failed".to_string());
-            return Err(UniversalNftError::TokenBurnFailed.into());
+        match token::burn(burn_ctx, 1) {
+            Ok(_) => {
+                msg!("✅ NFT burned successfully on Solana");
+                transfer_record.status = TransferStatus::InProgress;
+            },
+            Err(e) => {
+                msg!("❌ NFT burn failed: {:?}", e);
+                transfer_record.status = TransferStatus::Failed;
+                transfer_record.error_message = Some("Burn failed".to_string());
+                return Err(UniversalNftError::TokenBurnFailed.into());
+            }
         }
     }
 
@@ -147,17 +260,33 @@ pub fn transfer_cross_chain(
     // Update program state
     program_state.total_transfers += 1;
 
-    // Create message data for ZetaChain Gateway
-    let message_data = cross_chain_message.try_to_vec()?;
-    msg!("Prepared message data of {} bytes for ZetaChain Gateway", message_data.len());
+    // Create message data for ZetaChain Gateway in the destination chain's codec
+    let message_data = match ctx.accounts.chain_config.codec {
+        MessageCodec::Borsh => encode_cross_chain_message(&cross_chain_message)?,
+        MessageCodec::Abi => encode_universal_nft_payload(
+            &UniversalNftPayload::from_message(&cross_chain_message),
+        ),
+    };
+    msg!(
+        "Prepared {:?} message data of {} bytes for ZetaChain Gateway",
+        ctx.accounts.chain_config.codec,
+        message_data.len()
+    );
 
-    // Create comprehensive revert options for failed transfers
+    // Create revert options for failed transfers from the recorded choices
     let revert_options = RevertOptions {
-        revert_address: ctx.accounts.owner.key(),
-        abort_address: solana_to_evm_address(&ctx.accounts.owner.key()),
-        call_on_revert: true,
-        revert_message: create_revert_message(&nft_origin.token_id, &ctx.accounts.owner.key()),
-        on_revert_gas_limit: 200_000, // Increased gas for NFT re-minting operations
+        revert_address: transfer_record.revert_address,
+        abort_address: transfer_record.abort_address,
+        call_on_revert: transfer_record.call_on_revert,
+        revert_message: encode_revert_context(&RevertContext {
+            version: REVERT_CONTEXT_VERSION,
+            token_id: nft_origin.token_id,
+            original_owner: ctx.accounts.owner.key(),
+            mint: ctx.accounts.mint.key(),
+            transfer_record: transfer_record.key(),
+            metadata_hash: transfer_record.metadata_hash,
+        })?,
+        on_revert_gas_limit: transfer_record.on_revert_gas_limit,
     };
 
     msg!("🛡️ Revert protection configured:");
@@ -171,17 +300,20 @@ pub fn transfer_cross_chain(
     msg!("  Destination chain: {}", destination_chain_id);
     msg!("  Recipient: {:?}", recipient);
     msg!("  Message size: {} bytes", message_data.len());
+    msg!("  Gas deposit: {} lamports", ctx.accounts.chain_config.gas_deposit);
     msg!("  Revert address: {}", revert_options.revert_address);
 
     // Integrate with ZetaChain Solana Gateway using proper instruction format
-    let gateway_instruction = create_gateway_deposit_and_call_instruction(
+    let gateway_instruction = deposit_and_call_instruction(
         &ctx.accounts.gateway.key(),
         &ctx.accounts.gateway_pda.key(),
         &ctx.accounts.owner.key(),
-        0, // No SOL amount for NFT transfer
-        recipient,
-        message_data,
-        Some(revert_options),
+        &DepositAndCallArgs {
+            amount: ctx.accounts.chain_config.gas_deposit,
+            receiver: recipient,
+            message: message_data,
+            revert_options: Some(revert_options),
+        },
     )?;
 
     // Call ZetaChain gateway to initiate cross-chain transfer
@@ -207,6 +339,7 @@ pub fn transfer_cross_chain(
         sender: ctx.accounts.owner.key(),
         recipient,
         transfer_count: nft_origin.transfer_count,
+        fee,
         timestamp: Clock::get()?.unix_timestamp,
     });
 
@@ -220,15 +353,8 @@ pub fn transfer_cross_chain(
     Ok(())
 }
 
-fn solana_to_evm_address(pubkey: &Pubkey) -> [u8; 20] {
-    let mut evm_address = [0u8; 20];
-    let pubkey_bytes = pubkey.to_bytes();
-    // Take the last 20 bytes of the Solana pubkey to create an EVM-style address
-    evm_address.copy_from_slice(&pubkey_bytes[12..32]);
-    evm_address
-}
-
-fn create_enhanced_transfer_metadata(token_id: &[u8; 32], mint: &Pubkey) -> NftMetadata {
+/// Metadata sent with an outbound transfer, hashed into its revert context
+pub(crate) fn create_enhanced_transfer_metadata(token_id: &[u8; 32], mint: &Pubkey) -> NftMetadata {
     let token_id_hex = hex::encode(token_id);
     let short_id = &token_id_hex[0..8];
     
@@ -251,62 +377,6 @@ fn create_enhanced_transfer_metadata(token_id: &[u8; 32], mint: &Pubkey) -> NftM
     }
 }
 
-fn create_revert_message(token_id: &[u8; 32], owner: &Pubkey) -> Vec<u8> {
-    let message = format!(
-        "REVERT_NFT_TRANSFER|token_id:{}|original_owner:{}|timestamp:{}",
-        hex::encode(token_id),
-        owner.to_string(),
-        Clock::get().unwrap().unix_timestamp
-    );
-    message.into_bytes()
-}
-
-fn create_gateway_deposit_and_call_instruction(
-    gateway_program: &Pubkey,
-    gateway_pda: &Pubkey,
-    payer: &Pubkey,
-    amount: u64,
-    receiver: [u8; 32],
-    message: Vec<u8>,
-    revert_options: Option<RevertOptions>,
-) -> Result<anchor_lang::solana_program::instruction::Instruction> {
-    use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
-    
-    // ZetaChain gateway instruction discriminator for deposit_and_call
-    let mut instruction_data = vec![0x66, 0x87, 0x6a, 0x4d, 0x1c, 0x9a, 0x8b, 0x13]; // deposit_and_call discriminator
-    
-    // Serialize parameters according to ZetaChain gateway format
-    instruction_data.extend_from_slice(&amount.to_le_bytes());
-    instruction_data.extend_from_slice(&receiver);
-    
-    // Message length and data
-    instruction_data.extend_from_slice(&(message.len() as u32).to_le_bytes());
-    instruction_data.extend(message);
-    
-    // Revert options (simplified for now)
-    if let Some(revert_opts) = revert_options {
-        instruction_data.push(1); // Some
-        instruction_data.extend_from_slice(&revert_opts.revert_address.to_bytes());
-        instruction_data.extend_from_slice(&revert_opts.abort_address);
-        instruction_data.push(if revert_opts.call_on_revert { 1 } else { 0 });
-        instruction_data.extend_from_slice(&(revert_opts.revert_message.len() as u32).to_le_bytes());
-        instruction_data.extend(revert_opts.revert_message);
-        instruction_data.extend_from_slice(&revert_opts.on_revert_gas_limit.to_le_bytes());
-    } else {
-        instruction_data.push(0); // None
-    }
-    
-    Ok(Instruction {
-        program_id: *gateway_program,
-        accounts: vec![
-            AccountMeta::new(*gateway_pda, false),
-            AccountMeta::new(*payer, true),
-            AccountMeta::new_readonly(anchor_lang::solana_program::system_program::id(), false),
-        ],
-        data: instruction_data,
-    })
-}
-
 #[event]
 pub struct CrossChainTransferEvent {
     pub token_id: [u8; 32],
@@ -315,5 +385,7 @@ pub struct CrossChainTransferEvent {
     pub sender: Pubkey,
     pub recipient: [u8; 32],
     pub transfer_count: u64,
+    /// Protocol fee paid into the treasury
+    pub fee: u64,
     pub timestamp: i64,
 }
\ No newline at end of file
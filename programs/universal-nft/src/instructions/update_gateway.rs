@@ -13,38 +13,176 @@ pub struct UpdateGateway<'info> {
     pub program_state: Account<'info, ProgramState>,
     
     pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelGatewayUpdate<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = program_state.can_pause(&signer.key()) @ UniversalNftError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
     
-    /// CHECK: New gateway address will be validated
-    pub new_gateway: AccountInfo<'info>,
+    /// Authority or pauser, so a watcher can veto a rogue update
+    pub signer: Signer<'info>,
 }
 
-pub fn update_gateway(
+/// Queues `new_gateway` to replace the trusted gateway once the update delay
+/// has elapsed.
+pub fn queue_gateway_update(
     ctx: Context<UpdateGateway>,
     new_gateway: Pubkey,
 ) -> Result<()> {
     let program_state = &mut ctx.accounts.program_state;
     
-    require!(new_gateway != Pubkey::default(), UniversalNftError::InvalidGateway);
-    
-    let old_gateway = program_state.gateway;
-    program_state.gateway = new_gateway;
+    let eta = program_state.queue_gateway(new_gateway, Clock::get()?.unix_timestamp)?;
     
-    msg!("Gateway updated");
-    msg!("Old gateway: {}", old_gateway);
-    msg!("New gateway: {}", new_gateway);
+    msg!("Gateway update queued");
+    msg!("Current gateway: {}", program_state.gateway);
+    msg!("Queued gateway: {}", new_gateway);
+    msg!("ETA: {}", eta);
     
-    emit!(GatewayUpdatedEvent {
-        old_gateway,
+    emit!(GatewayUpdateQueuedEvent {
+        current_gateway: program_state.gateway,
         new_gateway,
+        eta,
         authority: ctx.accounts.authority.key(),
     });
     
     Ok(())
 }
 
+/// Applies the queued gateway or update delay once its ETA has passed
+pub fn execute_gateway_update(ctx: Context<UpdateGateway>) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+    
+    let old_gateway = program_state.gateway;
+    let old_delay = program_state.gateway_update_delay;
+    let gateway_queued = program_state.pending_gateway != Pubkey::default();
+    program_state.execute_queued_update(Clock::get()?.unix_timestamp)?;
+    
+    if gateway_queued {
+        msg!("Gateway updated");
+        msg!("Old gateway: {}", old_gateway);
+        msg!("New gateway: {}", program_state.gateway);
+        
+        emit!(GatewayUpdatedEvent {
+            old_gateway,
+            new_gateway: program_state.gateway,
+            authority: ctx.accounts.authority.key(),
+        });
+    } else {
+        msg!("Gateway update delay updated");
+        msg!("Old delay: {} seconds", old_delay);
+        msg!("New delay: {} seconds", program_state.gateway_update_delay);
+        
+        emit!(GatewayUpdateDelayUpdatedEvent {
+            old_delay,
+            new_delay: program_state.gateway_update_delay,
+            authority: ctx.accounts.authority.key(),
+        });
+    }
+    
+    Ok(())
+}
+
+/// Drops the queued gateway or update delay
+pub fn cancel_gateway_update(ctx: Context<CancelGatewayUpdate>) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+    
+    let cancelled_gateway = program_state.pending_gateway;
+    let cancelled_delay = program_state.pending_gateway_update_delay;
+    program_state.cancel_queued_update()?;
+    
+    msg!("Gateway update cancelled");
+    msg!("Cancelled gateway: {}", cancelled_gateway);
+    msg!("Cancelled delay: {} seconds", cancelled_delay);
+    
+    emit!(GatewayUpdateCancelledEvent {
+        cancelled_gateway,
+        cancelled_delay,
+        signer: ctx.accounts.signer.key(),
+    });
+    
+    Ok(())
+}
+
+/// Raises the delay applied to gateway updates queued from now on, or queues
+/// a lower one behind the current delay
+pub fn set_gateway_update_delay(
+    ctx: Context<UpdateGateway>,
+    delay: i64,
+) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+    
+    let old_delay = program_state.gateway_update_delay;
+    match program_state.change_gateway_update_delay(delay, Clock::get()?.unix_timestamp)? {
+        None => {
+            msg!("Gateway update delay updated");
+            msg!("Old delay: {} seconds", old_delay);
+            msg!("New delay: {} seconds", delay);
+            
+            emit!(GatewayUpdateDelayUpdatedEvent {
+                old_delay,
+                new_delay: delay,
+                authority: ctx.accounts.authority.key(),
+            });
+        }
+        Some(eta) => {
+            msg!("Gateway update delay decrease queued");
+            msg!("Current delay: {} seconds", old_delay);
+            msg!("Queued delay: {} seconds", delay);
+            msg!("ETA: {}", eta);
+            
+            emit!(GatewayUpdateDelayQueuedEvent {
+                current_delay: old_delay,
+                new_delay: delay,
+                eta,
+                authority: ctx.accounts.authority.key(),
+            });
+        }
+    }
+    
+    Ok(())
+}
+
+#[event]
+pub struct GatewayUpdateQueuedEvent {
+    pub current_gateway: Pubkey,
+    pub new_gateway: Pubkey,
+    pub eta: i64,
+    pub authority: Pubkey,
+}
+
 #[event]
 pub struct GatewayUpdatedEvent {
     pub old_gateway: Pubkey,
     pub new_gateway: Pubkey,
     pub authority: Pubkey,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct GatewayUpdateCancelledEvent {
+    /// Zero if a delay change was cancelled
+    pub cancelled_gateway: Pubkey,
+    /// Zero if a gateway change was cancelled
+    pub cancelled_delay: i64,
+    pub signer: Pubkey,
+}
+
+#[event]
+pub struct GatewayUpdateDelayUpdatedEvent {
+    pub old_delay: i64,
+    pub new_delay: i64,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct GatewayUpdateDelayQueuedEvent {
+    pub current_delay: i64,
+    pub new_delay: i64,
+    pub eta: i64,
+    pub authority: Pubkey,
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        has_one = authority @ UniversalNftError::Unauthorized
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Any account may receive the withdrawn lamports
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+/// Moves `amount` lamports out of the treasury, which stays rent exempt
+pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    let treasury = ctx.accounts.treasury.to_account_info();
+    let recipient = ctx.accounts.recipient.to_account_info();
+    
+    let minimum = Rent::get()?.minimum_balance(treasury.data_len());
+    let available = treasury.lamports().saturating_sub(minimum);
+    require!(amount <= available, UniversalNftError::InsufficientTreasuryBalance);
+    
+    // The treasury is owned by this program, so it is debited directly
+    **treasury.try_borrow_mut_lamports()? -= amount;
+    **recipient.try_borrow_mut_lamports()? += amount;
+    
+    msg!("Treasury withdrawal");
+    msg!("Amount: {} lamports", amount);
+    msg!("Recipient: {}", recipient.key());
+    msg!("Remaining withdrawable: {} lamports", available - amount);
+    
+    emit!(TreasuryWithdrawnEvent {
+        amount,
+        recipient: recipient.key(),
+        authority: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct TreasuryWithdrawnEvent {
+    pub amount: u64,
+    pub recipient: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
@@ -1,11 +1,17 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::sysvar::instructions::get_instruction_relative;
 use mpl_token_metadata::types::Creator;
 
 declare_id!("89BWBddtdbMriDFjotkPyzDvcehdJaZMvPYU1LifVjRc");
 
 pub mod errors;
 pub mod state;
+pub mod message;
+pub mod abi;
+pub mod gateway;
+pub mod token_id;
+pub mod nft;
+pub mod secp256k1;
+pub mod merkle;
 pub mod instructions;
 
 use instructions::*;
@@ -35,12 +41,13 @@ pub mod universal_nft {
     }
 
     /// Mint a new NFT on Solana
-    pub fn mint_nft(
-        ctx: Context<MintNft>,
+    pub fn mint_nft<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintNft<'info>>,
         name: String,
         symbol: String,
         uri: String,
         creators: Option<Vec<Creator>>,
+        allowlist_proof: Option<crate::state::AllowlistProof>,
     ) -> Result<()> {
         msg!("=== UNIVERSAL NFT MINT ===");
         msg!("Program ID: {}", ctx.program_id);
@@ -51,7 +58,7 @@ pub mod universal_nft {
         msg!("Symbol: {}", symbol);
         msg!("URI: {}", uri);
         
-        let result = instructions::mint_nft(ctx, name, symbol, uri, creators);
+        let result = instructions::mint_nft(ctx, name, symbol, uri, creators, allowlist_proof);
         
         match &result {
             Ok(_) => msg!("✅ Mint completed successfully"),
@@ -61,7 +68,30 @@ pub mod universal_nft {
         result
     }
 
-    /// Mint a new NFT on Solana (simplified version for testing)
+    /// Mint several NFTs in one instruction (authority or minter)
+    pub fn mint_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintBatch<'info>>,
+        symbol: String,
+        creators: Option<Vec<Creator>>,
+        items: Vec<BatchMintItem>,
+    ) -> Result<()> {
+        msg!("=== UNIVERSAL NFT MINT BATCH ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Payer: {}", ctx.accounts.payer.key());
+        msg!("Symbol: {}", symbol);
+        msg!("Items: {}", items.len());
+        
+        let result = instructions::mint_batch(ctx, symbol, creators, items);
+        
+        match &result {
+            Ok(_) => msg!("✅ Batch mint completed successfully"),
+            Err(e) => msg!("❌ Batch mint failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    // Mint a new NFT on Solana (simplified version for testing)
     // Removed simple mint to avoid duplication/confusion; use mint_nft instead
 
     /// Transfer NFT to another chain via ZetaChain
@@ -69,6 +99,7 @@ pub mod universal_nft {
         ctx: Context<TransferCrossChain>,
         destination_chain_id: u64,
         recipient: [u8; 32],
+        revert_options: Option<crate::state::TransferRevertOptions>,
     ) -> Result<()> {
         msg!("=== UNIVERSAL NFT CROSS-CHAIN TRANSFER ===");
         msg!("Program ID: {}", ctx.program_id);
@@ -77,7 +108,7 @@ pub mod universal_nft {
         msg!("Destination Chain ID: {}", destination_chain_id);
         msg!("Recipient: {:?}", recipient);
         
-        let result = instructions::transfer_cross_chain(ctx, destination_chain_id, recipient);
+        let result = instructions::transfer_cross_chain(ctx, destination_chain_id, recipient, revert_options);
         
         match &result {
             Ok(_) => msg!("✅ Cross-chain transfer completed successfully"),
@@ -91,6 +122,7 @@ pub mod universal_nft {
     pub fn receive_cross_chain(
         ctx: Context<ReceiveCrossChain>,
         token_id: [u8; 32],
+        origin_chain_id: u64,
         name: String,
         symbol: String,
         uri: String,
@@ -99,12 +131,20 @@ pub mod universal_nft {
         msg!("=== UNIVERSAL NFT CROSS-CHAIN RECEIVE ===");
         msg!("Program ID: {}", ctx.program_id);
         msg!("Token ID: {:?}", token_id);
+        msg!("Origin chain: {}", origin_chain_id);
         msg!("Name: {}", name);
         msg!("Symbol: {}", symbol);
         msg!("URI: {}", uri);
-        msg!("Gateway: {}", ctx.accounts.gateway.key());
+        msg!("Gateway PDA: {}", ctx.accounts.gateway_pda.key());
         
-        let result = instructions::receive_cross_chain(ctx, token_id, name, symbol, uri, creators);
+        // Verify caller is the ZetaChain gateway
+        gateway::verify_gateway_caller(
+            &ctx.accounts.program_state.gateway,
+            &ctx.accounts.gateway_pda,
+            &ctx.accounts.instruction_sysvar_account,
+        )?;
+        
+        let result = instructions::receive_cross_chain(ctx, token_id, origin_chain_id, name, symbol, uri, creators);
         
         match &result {
             Ok(_) => msg!("✅ Cross-chain receive completed successfully"),
@@ -128,14 +168,11 @@ pub mod universal_nft {
         msg!("Data length: {}", data.len());
         
         // Verify caller is the ZetaChain gateway
-        let current_ix = get_instruction_relative(0, &ctx.accounts.instruction_sysvar_account.to_account_info())?;
-        msg!("Caller program: {}", current_ix.program_id);
-        msg!("Expected gateway: {}", ZETACHAIN_GATEWAY_ID);
-        
-        require!(
-            current_ix.program_id == ZETACHAIN_GATEWAY_ID,
-            crate::errors::UniversalNftError::InvalidGatewayCaller
-        );
+        gateway::verify_gateway_caller(
+            &ctx.accounts.program_state.gateway,
+            &ctx.accounts.gateway_pda,
+            &ctx.accounts.instruction_sysvar_account,
+        )?;
         
         let result = instructions::on_call(ctx, amount, sender, data);
         
@@ -160,6 +197,13 @@ pub mod universal_nft {
         msg!("Sender: {}", sender);
         msg!("Data length: {}", data.len());
         
+        // Verify caller is the ZetaChain gateway
+        gateway::verify_gateway_caller(
+            &ctx.accounts.program_state.gateway,
+            &ctx.accounts.gateway_pda,
+            &ctx.accounts.instruction_sysvar_account,
+        )?;
+        
         let result = instructions::on_revert(ctx, amount, sender, data);
         
         match &result {
@@ -170,18 +214,83 @@ pub mod universal_nft {
         result
     }
 
-    /// Update gateway address (admin only)
-    pub fn update_gateway(
+    /// Called by ZetaChain gateway when a cross-chain call is aborted
+    pub fn on_abort(
+        ctx: Context<OnAbort>,
+        amount: u64,
+        sender: Pubkey,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        msg!("=== UNIVERSAL NFT ON_ABORT (ZetaChain Gateway) ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Amount: {}", amount);
+        msg!("Sender: {}", sender);
+        msg!("Data length: {}", data.len());
+        
+        // Verify caller is the ZetaChain gateway
+        gateway::verify_gateway_caller(
+            &ctx.accounts.program_state.gateway,
+            &ctx.accounts.gateway_pda,
+            &ctx.accounts.instruction_sysvar_account,
+        )?;
+        
+        let result = instructions::on_abort(ctx, amount, sender, data);
+        
+        match &result {
+            Ok(_) => msg!("✅ on_abort completed successfully"),
+            Err(e) => msg!("❌ on_abort failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Restore the NFT of a transfer on_abort could not identify (admin only)
+    pub fn recover_aborted_transfer(ctx: Context<RecoverAbortedTransfer>) -> Result<()> {
+        msg!("=== UNIVERSAL NFT RECOVER ABORTED TRANSFER ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Authority: {}", ctx.accounts.authority.key());
+        msg!("Transfer record: {}", ctx.accounts.transfer_record.key());
+        
+        let result = instructions::recover_aborted_transfer(ctx);
+        
+        match &result {
+            Ok(_) => msg!("✅ Aborted transfer recovery completed successfully"),
+            Err(e) => msg!("❌ Aborted transfer recovery failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Queue a gateway change, applied after the update delay (admin only)
+    pub fn queue_gateway_update(
         ctx: Context<UpdateGateway>,
         new_gateway: Pubkey,
     ) -> Result<()> {
-        msg!("=== UNIVERSAL NFT UPDATE GATEWAY ===");
+        msg!("=== UNIVERSAL NFT QUEUE GATEWAY UPDATE ===");
         msg!("Program ID: {}", ctx.program_id);
         msg!("Authority: {}", ctx.accounts.authority.key());
-        msg!("Old Gateway: {}", ctx.accounts.program_state.gateway);
+        msg!("Current Gateway: {}", ctx.accounts.program_state.gateway);
         msg!("New Gateway: {}", new_gateway);
         
-        let result = instructions::update_gateway(ctx, new_gateway);
+        let result = instructions::queue_gateway_update(ctx, new_gateway);
+        
+        match &result {
+            Ok(_) => msg!("✅ Gateway update queued successfully"),
+            Err(e) => msg!("❌ Gateway update queueing failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Apply the queued gateway or delay change once its delay has elapsed (admin only)
+    pub fn execute_gateway_update(ctx: Context<UpdateGateway>) -> Result<()> {
+        msg!("=== UNIVERSAL NFT EXECUTE GATEWAY UPDATE ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Authority: {}", ctx.accounts.authority.key());
+        msg!("Old Gateway: {}", ctx.accounts.program_state.gateway);
+        msg!("New Gateway: {}", ctx.accounts.program_state.pending_gateway);
+        
+        let result = instructions::execute_gateway_update(ctx);
         
         match &result {
             Ok(_) => msg!("✅ Gateway update completed successfully"),
@@ -191,6 +300,466 @@ pub mod universal_nft {
         result
     }
 
+    /// Cancel the queued gateway or delay change (authority or pauser)
+    pub fn cancel_gateway_update(ctx: Context<CancelGatewayUpdate>) -> Result<()> {
+        msg!("=== UNIVERSAL NFT CANCEL GATEWAY UPDATE ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Signer: {}", ctx.accounts.signer.key());
+        msg!("Queued Gateway: {}", ctx.accounts.program_state.pending_gateway);
+        
+        let result = instructions::cancel_gateway_update(ctx);
+        
+        match &result {
+            Ok(_) => msg!("✅ Gateway update cancelled successfully"),
+            Err(e) => msg!("❌ Gateway update cancellation failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Raise the delay between queueing and applying a gateway change, or queue
+    /// a lower one (admin only)
+    pub fn set_gateway_update_delay(
+        ctx: Context<UpdateGateway>,
+        delay: i64,
+    ) -> Result<()> {
+        msg!("=== UNIVERSAL NFT SET GATEWAY UPDATE DELAY ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Authority: {}", ctx.accounts.authority.key());
+        msg!("Delay: {} seconds", delay);
+        
+        let result = instructions::set_gateway_update_delay(ctx, delay);
+        
+        match &result {
+            Ok(_) => msg!("✅ Gateway update delay set successfully"),
+            Err(e) => msg!("❌ Gateway update delay change failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Configure messaging for a connected chain (authority or chain manager)
+    pub fn set_chain_config(
+        ctx: Context<SetChainConfig>,
+        chain_id: u64,
+        codec: crate::state::MessageCodec,
+        enabled: bool,
+        min_revert_gas_limit: u64,
+        max_revert_gas_limit: u64,
+    ) -> Result<()> {
+        msg!("=== UNIVERSAL NFT SET CHAIN CONFIG ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Authority: {}", ctx.accounts.authority.key());
+        msg!("Chain ID: {}", chain_id);
+        
+        let result = instructions::set_chain_config(
+            ctx,
+            chain_id,
+            codec,
+            enabled,
+            min_revert_gas_limit,
+            max_revert_gas_limit,
+        );
+        
+        match &result {
+            Ok(_) => msg!("✅ Chain config update completed successfully"),
+            Err(e) => msg!("❌ Chain config update failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Set the gas deposit and protocol fee of transfers to a chain (authority or fee manager)
+    pub fn set_chain_fees(
+        ctx: Context<SetChainFees>,
+        chain_id: u64,
+        gas_deposit: u64,
+        fee_lamports: u64,
+        fee_bps: u16,
+    ) -> Result<()> {
+        msg!("=== UNIVERSAL NFT SET CHAIN FEES ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Fee manager: {}", ctx.accounts.fee_manager.key());
+        msg!("Chain ID: {}", chain_id);
+        
+        let result = instructions::set_chain_fees(ctx, chain_id, gas_deposit, fee_lamports, fee_bps);
+        
+        match &result {
+            Ok(_) => msg!("✅ Chain fees update completed successfully"),
+            Err(e) => msg!("❌ Chain fees update failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Set the universal NFT contract of a chain (authority or chain manager)
+    pub fn set_universal_contract(
+        ctx: Context<SetUniversalContract>,
+        chain_id: u64,
+        universal_contract: [u8; 20],
+    ) -> Result<()> {
+        msg!("=== UNIVERSAL NFT SET UNIVERSAL CONTRACT ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Authority: {}", ctx.accounts.authority.key());
+        msg!("Chain ID: {}", chain_id);
+        
+        let result = instructions::set_universal_contract(ctx, chain_id, universal_contract);
+        
+        match &result {
+            Ok(_) => msg!("✅ Universal contract update completed successfully"),
+            Err(e) => msg!("❌ Universal contract update failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Withdraw collected fees from the treasury (admin only)
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        msg!("=== UNIVERSAL NFT WITHDRAW TREASURY ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Authority: {}", ctx.accounts.authority.key());
+        msg!("Amount: {} lamports", amount);
+        
+        let result = instructions::withdraw_treasury(ctx, amount);
+        
+        match &result {
+            Ok(_) => msg!("✅ Treasury withdrawal completed successfully"),
+            Err(e) => msg!("❌ Treasury withdrawal failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Quote the cost of a transfer to a chain as return data (read-only)
+    pub fn quote_transfer(
+        ctx: Context<QuoteTransfer>,
+        destination_chain_id: u64,
+    ) -> Result<crate::instructions::TransferQuote> {
+        msg!("=== UNIVERSAL NFT QUOTE TRANSFER ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Destination Chain ID: {}", destination_chain_id);
+        
+        let result = instructions::quote_transfer(ctx, destination_chain_id);
+        
+        match &result {
+            Ok(_) => msg!("✅ Transfer quote completed successfully"),
+            Err(e) => msg!("❌ Transfer quote failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Set the price, supply, window and proceeds of minting (admin only)
+    pub fn set_mint_config(
+        ctx: Context<SetMintConfig>,
+        settings: crate::state::MintSettings,
+    ) -> Result<()> {
+        msg!("=== UNIVERSAL NFT SET MINT CONFIG ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Authority: {}", ctx.accounts.authority.key());
+        
+        let result = instructions::set_mint_config(ctx, settings);
+        
+        match &result {
+            Ok(_) => msg!("✅ Mint config update completed successfully"),
+            Err(e) => msg!("❌ Mint config update failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Create a claims page of the current allowlist (anyone may pay)
+    pub fn init_allowlist_claims(ctx: Context<InitAllowlistClaims>, page: u32) -> Result<()> {
+        msg!("=== UNIVERSAL NFT INIT ALLOWLIST CLAIMS ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Payer: {}", ctx.accounts.payer.key());
+        msg!("Page: {}", page);
+        
+        let result = instructions::init_allowlist_claims(ctx, page);
+        
+        match &result {
+            Ok(_) => msg!("✅ Allowlist claims page created successfully"),
+            Err(e) => msg!("❌ Allowlist claims page creation failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Link the caller's wallet to an EVM address proven with a secp256k1 signature
+    pub fn link_evm_address(
+        ctx: Context<LinkEvmAddress>,
+        evm_address: [u8; 20],
+    ) -> Result<()> {
+        msg!("=== UNIVERSAL NFT LINK EVM ADDRESS ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Wallet: {}", ctx.accounts.wallet.key());
+        msg!("EVM address: {:?}", evm_address);
+        
+        let result = instructions::link_evm_address(ctx, evm_address);
+        
+        match &result {
+            Ok(_) => msg!("✅ EVM address link completed successfully"),
+            Err(e) => msg!("❌ EVM address link failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Queue a TSS address rotation, applied after the gateway update delay,
+    /// or disable signed execution at once with a zero address (admin only)
+    pub fn queue_tss_address_update(
+        ctx: Context<SetTssAddress>,
+        tss_address: [u8; 20],
+    ) -> Result<()> {
+        msg!("=== UNIVERSAL NFT QUEUE TSS ADDRESS UPDATE ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Authority: {}", ctx.accounts.authority.key());
+        msg!("TSS address: {:?}", tss_address);
+        
+        let result = instructions::queue_tss_address_update(ctx, tss_address);
+        
+        match &result {
+            Ok(_) => msg!("✅ TSS address update queued successfully"),
+            Err(e) => msg!("❌ TSS address update queueing failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Apply the queued TSS address once its delay has elapsed (admin only)
+    pub fn execute_tss_address_update(ctx: Context<SetTssAddress>) -> Result<()> {
+        msg!("=== UNIVERSAL NFT EXECUTE TSS ADDRESS UPDATE ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Authority: {}", ctx.accounts.authority.key());
+        msg!("Pending TSS address: {:?}", ctx.accounts.program_state.pending_tss_address);
+        
+        let result = instructions::execute_tss_address_update(ctx);
+        
+        match &result {
+            Ok(_) => msg!("✅ TSS address update completed successfully"),
+            Err(e) => msg!("❌ TSS address update failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Drop the queued TSS address (admin or pauser)
+    pub fn cancel_tss_address_update(ctx: Context<CancelTssAddressUpdate>) -> Result<()> {
+        msg!("=== UNIVERSAL NFT CANCEL TSS ADDRESS UPDATE ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Signer: {}", ctx.accounts.signer.key());
+        msg!("Pending TSS address: {:?}", ctx.accounts.program_state.pending_tss_address);
+        
+        let result = instructions::cancel_tss_address_update(ctx);
+        
+        match &result {
+            Ok(_) => msg!("✅ TSS address update cancellation completed successfully"),
+            Err(e) => msg!("❌ TSS address update cancellation failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Execute an inbound message authorized by a TSS signature
+    pub fn execute_signed(
+        ctx: Context<ExecuteSigned>,
+        nonce: u64,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        msg!("=== UNIVERSAL NFT EXECUTE SIGNED ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Payer: {}", ctx.accounts.payer.key());
+        msg!("Nonce: {}", nonce);
+        msg!("Data length: {}", data.len());
+        
+        let result = instructions::execute_signed(ctx, nonce, data);
+        
+        match &result {
+            Ok(_) => msg!("✅ Signed execution completed successfully"),
+            Err(e) => msg!("❌ Signed execution failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Pause the operations selected by the `PAUSE_*` bits (authority or pauser)
+    pub fn pause(ctx: Context<SetPaused>, flags: u8) -> Result<()> {
+        msg!("=== UNIVERSAL NFT PAUSE ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Signer: {}", ctx.accounts.signer.key());
+        msg!("Flags: {:#05b}", flags);
+        
+        let result = instructions::pause(ctx, flags);
+        
+        match &result {
+            Ok(_) => msg!("✅ Pause completed successfully"),
+            Err(e) => msg!("❌ Pause failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Resume the operations selected by the `PAUSE_*` bits (authority or pauser)
+    pub fn unpause(ctx: Context<SetPaused>, flags: u8) -> Result<()> {
+        msg!("=== UNIVERSAL NFT UNPAUSE ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Signer: {}", ctx.accounts.signer.key());
+        msg!("Flags: {:#05b}", flags);
+        
+        let result = instructions::unpause(ctx, flags);
+        
+        match &result {
+            Ok(_) => msg!("✅ Unpause completed successfully"),
+            Err(e) => msg!("❌ Unpause failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Queue a new key allowed to pause besides the authority, applied after
+    /// the gateway update delay (admin only)
+    pub fn queue_pauser_update(ctx: Context<SetPauser>, pauser: Pubkey) -> Result<()> {
+        msg!("=== UNIVERSAL NFT QUEUE PAUSER UPDATE ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Authority: {}", ctx.accounts.authority.key());
+        msg!("Pauser: {}", pauser);
+        
+        let result = instructions::queue_pauser_update(ctx, pauser);
+        
+        match &result {
+            Ok(_) => msg!("✅ Pauser update queued successfully"),
+            Err(e) => msg!("❌ Pauser update queueing failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Apply the queued pauser once its delay has elapsed (admin only)
+    pub fn execute_pauser_update(ctx: Context<SetPauser>) -> Result<()> {
+        msg!("=== UNIVERSAL NFT EXECUTE PAUSER UPDATE ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Authority: {}", ctx.accounts.authority.key());
+        msg!("Queued Pauser: {}", ctx.accounts.program_state.pending_pauser);
+        
+        let result = instructions::execute_pauser_update(ctx);
+        
+        match &result {
+            Ok(_) => msg!("✅ Pauser update completed successfully"),
+            Err(e) => msg!("❌ Pauser update failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Drop the queued pauser (admin only)
+    pub fn cancel_pauser_update(ctx: Context<SetPauser>) -> Result<()> {
+        msg!("=== UNIVERSAL NFT CANCEL PAUSER UPDATE ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Authority: {}", ctx.accounts.authority.key());
+        msg!("Queued Pauser: {}", ctx.accounts.program_state.pending_pauser);
+        
+        let result = instructions::cancel_pauser_update(ctx);
+        
+        match &result {
+            Ok(_) => msg!("✅ Pauser update cancelled successfully"),
+            Err(e) => msg!("❌ Pauser update cancellation failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Propose a new program authority, which must accept (admin only)
+    pub fn propose_authority(
+        ctx: Context<ProposeAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        msg!("=== UNIVERSAL NFT PROPOSE AUTHORITY ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Authority: {}", ctx.accounts.authority.key());
+        msg!("Proposed authority: {}", new_authority);
+        
+        let result = instructions::propose_authority(ctx, new_authority);
+        
+        match &result {
+            Ok(_) => msg!("✅ Authority proposal completed successfully"),
+            Err(e) => msg!("❌ Authority proposal failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Accept a proposed authority transfer (proposed authority only)
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        msg!("=== UNIVERSAL NFT ACCEPT AUTHORITY ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("New authority: {}", ctx.accounts.new_authority.key());
+        
+        let result = instructions::accept_authority(ctx);
+        
+        match &result {
+            Ok(_) => msg!("✅ Authority transfer completed successfully"),
+            Err(e) => msg!("❌ Authority transfer failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Assign or revoke an operational role (admin only)
+    pub fn set_role(
+        ctx: Context<SetRole>,
+        role: crate::state::Role,
+        holder: Pubkey,
+    ) -> Result<()> {
+        msg!("=== UNIVERSAL NFT SET ROLE ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Authority: {}", ctx.accounts.authority.key());
+        msg!("Role: {:?}", role);
+        msg!("Holder: {}", holder);
+        
+        let result = instructions::set_role(ctx, role, holder);
+        
+        match &result {
+            Ok(_) => msg!("✅ Role update completed successfully"),
+            Err(e) => msg!("❌ Role update failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Upgrade the program state account to the current layout (admin only)
+    pub fn migrate_state(ctx: Context<MigrateState>) -> Result<()> {
+        msg!("=== UNIVERSAL NFT MIGRATE STATE ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Authority: {}", ctx.accounts.authority.key());
+        msg!("Program State: {}", ctx.accounts.program_state.key());
+        
+        let result = instructions::migrate_state(ctx);
+        
+        match &result {
+            Ok(_) => msg!("✅ State migration completed successfully"),
+            Err(e) => msg!("❌ State migration failed: {:?}", e),
+        }
+        
+        result
+    }
+
+    /// Upgrade a chain config account to the current layout (authority or chain manager)
+    pub fn migrate_chain_config(ctx: Context<MigrateChainConfig>, chain_id: u64) -> Result<()> {
+        msg!("=== UNIVERSAL NFT MIGRATE CHAIN CONFIG ===");
+        msg!("Program ID: {}", ctx.program_id);
+        msg!("Authority: {}", ctx.accounts.authority.key());
+        msg!("Chain ID: {}", chain_id);
+        
+        let result = instructions::migrate_chain_config(ctx, chain_id);
+        
+        match &result {
+            Ok(_) => msg!("✅ Chain config migration completed successfully"),
+            Err(e) => msg!("❌ Chain config migration failed: {:?}", e),
+        }
+        
+        result
+    }
+
     // /// Trigger deposit to ZetaChain (for testing cross-chain functionality)
     // pub fn trigger_deposit(
     //     ctx: Context<TriggerDeposit>,
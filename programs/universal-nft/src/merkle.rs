@@ -0,0 +1,38 @@
+use anchor_lang::solana_program::keccak;
+
+/// Leaf of the allowlist tree: `keccak256(claimant || first_ticket || allowance)`,
+/// integers big-endian.
+///
+/// `claimant` is a Solana wallet, or an EVM address left-padded with zeros.
+/// An entry owns the claim tickets `first_ticket..first_ticket + allowance`;
+/// the tree builder assigns every entry its own range.
+pub fn allowlist_leaf(claimant: &[u8; 32], first_ticket: u32, allowance: u32) -> [u8; 32] {
+    keccak::hashv(&[
+        claimant,
+        &first_ticket.to_be_bytes(),
+        &allowance.to_be_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Allowlist claimant of an EVM address
+pub fn evm_claimant(address: &[u8; 20]) -> [u8; 32] {
+    let mut claimant = [0u8; 32];
+    claimant[12..].copy_from_slice(address);
+    claimant
+}
+
+/// Checks a proof for a tree hashing sorted pairs with keccak256, the layout
+/// of OpenZeppelin's `MerkleProof`
+pub fn verify_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| hash_pair(&node, sibling));
+    computed == *root
+}
+
+fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    if a <= b {
+        keccak::hashv(&[a, b]).to_bytes()
+    } else {
+        keccak::hashv(&[b, a]).to_bytes()
+    }
+}
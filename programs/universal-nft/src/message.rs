@@ -0,0 +1,223 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+use crate::state::*;
+use crate::errors::*;
+
+/// Magic bytes opening every cross-chain message envelope
+pub const MESSAGE_MAGIC: [u8; 4] = *b"UNFT";
+
+/// Version of messages sent before the envelope existed: a bare Borsh
+/// `LegacyCrossChainMessage` with no header
+pub const MESSAGE_VERSION_LEGACY: u8 = 0;
+
+/// Envelope version carrying a `CrossChainMessage` payload
+pub const MESSAGE_VERSION_V1: u8 = 1;
+
+/// Version written by this program for outbound messages
+pub const CURRENT_MESSAGE_VERSION: u8 = MESSAGE_VERSION_V1;
+
+/// Envelope header: magic (4) + version (1) + message type (1) + payload length (4)
+pub const ENVELOPE_HEADER_LEN: usize = 4 + 1 + 1 + 4;
+
+/// Envelope message type of an `AllowlistClaim`, outside the range of
+/// `CrossChainMessageType`
+pub const ALLOWLIST_CLAIM_MESSAGE_TYPE: u8 = 0x80;
+
+/// Version of the `RevertContext` layout written by this program
+pub const REVERT_CONTEXT_VERSION: u8 = 1;
+
+/// Encoded size of a `RevertContext`: version (1) + token ID, original
+/// owner, mint, transfer record and metadata hash (32 each)
+pub const REVERT_CONTEXT_LEN: usize = 1 + 5 * 32;
+
+/// Message layout sent as bare Borsh before the envelope and `origin_chain_id`
+/// were added; transfers initiated then may still be in flight
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct LegacyCrossChainMessage {
+    pub message_type: CrossChainMessageType,
+    pub token_id: [u8; 32],
+    pub source_chain_id: u64,
+    pub destination_chain_id: u64,
+    pub sender: [u8; 20],
+    pub recipient: [u8; 32],
+    pub metadata: NftMetadata,
+    pub timestamp: i64,
+}
+
+impl From<LegacyCrossChainMessage> for CrossChainMessage {
+    fn from(message: LegacyCrossChainMessage) -> Self {
+        CrossChainMessage {
+            message_type: message.message_type,
+            token_id: message.token_id,
+            source_chain_id: message.source_chain_id,
+            destination_chain_id: message.destination_chain_id,
+            sender: message.sender,
+            recipient: message.recipient,
+            metadata: message.metadata,
+            timestamp: message.timestamp,
+            // Legacy senders only forwarded NFTs they had minted themselves
+            origin_chain_id: message.source_chain_id,
+        }
+    }
+}
+
+/// Wraps a Borsh payload in a message envelope
+pub fn encode_envelope(version: u8, message_type: u8, payload: &[u8]) -> Vec<u8> {
+    let mut data = Vec::with_capacity(ENVELOPE_HEADER_LEN + payload.len());
+    data.extend_from_slice(&MESSAGE_MAGIC);
+    data.push(version);
+    data.push(message_type);
+    data.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    data.extend_from_slice(payload);
+    data
+}
+
+/// Encodes a message in the current envelope version
+pub fn encode_cross_chain_message(message: &CrossChainMessage) -> Result<Vec<u8>> {
+    let payload = message.try_to_vec()?;
+    Ok(encode_envelope(
+        CURRENT_MESSAGE_VERSION,
+        message.message_type.clone() as u8,
+        &payload,
+    ))
+}
+
+/// Decodes a `CrossChainMessage` and validates its structure.
+///
+/// Every supported envelope version is upgraded to the current message
+/// layout. Data without the envelope magic is decoded as a legacy message
+/// when its first byte is a message type, since legacy messages open with
+/// their type. The whole buffer must be consumed and the token ID must be
+/// set. Nothing is substituted for missing or malformed fields: any payload
+/// that does not describe a complete message is rejected with a specific
+/// error.
+pub fn decode_cross_chain_message(data: &[u8]) -> Result<CrossChainMessage> {
+    require!(!data.is_empty(), UniversalNftError::MessageTruncated);
+
+    let message: CrossChainMessage = if data.starts_with(&MESSAGE_MAGIC) {
+        decode_envelope(data)?
+    } else {
+        require!(
+            data[0] <= CrossChainMessageType::Revert as u8,
+            UniversalNftError::InvalidMessageMagic
+        );
+        decode_payload::<LegacyCrossChainMessage>(data)?.into()
+    };
+
+    require!(message.token_id != [0u8; 32], UniversalNftError::ZeroTokenId);
+
+    Ok(message)
+}
+
+fn decode_envelope(data: &[u8]) -> Result<CrossChainMessage> {
+    require!(data.len() >= ENVELOPE_HEADER_LEN, UniversalNftError::MessageTruncated);
+
+    let version = data[4];
+    let message_type_tag = data[5];
+    let payload_len = u32::from_le_bytes(data[6..10].try_into().unwrap()) as usize;
+    let payload = &data[ENVELOPE_HEADER_LEN..];
+
+    require!(
+        message_type_tag <= CrossChainMessageType::Revert as u8,
+        UniversalNftError::UnknownMessageType
+    );
+    require!(payload.len() >= payload_len, UniversalNftError::MessageTruncated);
+    require!(payload.len() == payload_len, UniversalNftError::InvalidCrossChainMessage);
+
+    let message = match version {
+        MESSAGE_VERSION_V1 => decode_payload::<CrossChainMessage>(payload)?,
+        _ => return Err(UniversalNftError::UnsupportedMessageVersion.into()),
+    };
+
+    require!(
+        message.message_type.clone() as u8 == message_type_tag,
+        UniversalNftError::InvalidCrossChainMessage
+    );
+
+    Ok(message)
+}
+
+/// Decodes a message delivered to this chain, additionally checking that it
+/// was addressed to `local_chain_id`.
+pub fn decode_inbound_message(data: &[u8], local_chain_id: u64) -> Result<CrossChainMessage> {
+    let message = decode_cross_chain_message(data)?;
+
+    require!(
+        message.destination_chain_id == local_chain_id,
+        UniversalNftError::WrongDestinationChain
+    );
+
+    Ok(message)
+}
+
+/// Encodes an allowlist claim in the current envelope version
+pub fn encode_allowlist_claim(claim: &AllowlistClaim) -> Result<Vec<u8>> {
+    Ok(encode_envelope(
+        CURRENT_MESSAGE_VERSION,
+        ALLOWLIST_CLAIM_MESSAGE_TYPE,
+        &claim.try_to_vec()?,
+    ))
+}
+
+/// Whether `data` is enveloped as an allowlist claim
+pub fn is_allowlist_claim(data: &[u8]) -> bool {
+    data.len() >= ENVELOPE_HEADER_LEN
+        && data[0..4] == MESSAGE_MAGIC
+        && data[5] == ALLOWLIST_CLAIM_MESSAGE_TYPE
+}
+
+/// Decodes an enveloped `AllowlistClaim`, consuming the whole buffer
+pub fn decode_allowlist_claim(data: &[u8]) -> Result<AllowlistClaim> {
+    require!(is_allowlist_claim(data), UniversalNftError::InvalidCrossChainMessage);
+    require!(
+        data[4] == CURRENT_MESSAGE_VERSION,
+        UniversalNftError::UnsupportedMessageVersion
+    );
+
+    let payload_len = u32::from_le_bytes(data[6..10].try_into().unwrap()) as usize;
+    let payload = &data[ENVELOPE_HEADER_LEN..];
+    require!(payload.len() >= payload_len, UniversalNftError::MessageTruncated);
+    require!(payload.len() == payload_len, UniversalNftError::InvalidCrossChainMessage);
+
+    let claim = decode_payload::<AllowlistClaim>(payload)?;
+    require!(claim.recipient != [0u8; 32], UniversalNftError::InvalidRecipient);
+
+    Ok(claim)
+}
+
+/// Hash identifying the metadata sent with a transfer
+pub fn metadata_hash(metadata: &NftMetadata) -> Result<[u8; 32]> {
+    Ok(keccak::hash(&metadata.try_to_vec()?).to_bytes())
+}
+
+/// Encodes a revert context for the gateway revert message
+pub fn encode_revert_context(context: &RevertContext) -> Result<Vec<u8>> {
+    Ok(context.try_to_vec()?)
+}
+
+/// Decodes a revert context, rejecting unknown versions and trailing bytes
+pub fn decode_revert_context(data: &[u8]) -> Result<RevertContext> {
+    require!(!data.is_empty(), UniversalNftError::MessageTruncated);
+    require!(
+        data[0] == REVERT_CONTEXT_VERSION,
+        UniversalNftError::UnsupportedMessageVersion
+    );
+    require!(data.len() >= REVERT_CONTEXT_LEN, UniversalNftError::MessageTruncated);
+    
+    let context = decode_payload::<RevertContext>(data)?;
+    require!(context.token_id != [0u8; 32], UniversalNftError::ZeroTokenId);
+    
+    Ok(context)
+}
+
+/// Decodes a Borsh payload, consuming it whole. Truncation is detected from
+/// recorded lengths before this is called, so any failure here means the
+/// payload itself is malformed.
+fn decode_payload<T: AnchorDeserialize>(payload: &[u8]) -> Result<T> {
+    let mut remaining = payload;
+    let value = T::deserialize(&mut remaining)
+        .map_err(|_| UniversalNftError::InvalidCrossChainMessage)?;
+    require!(remaining.is_empty(), UniversalNftError::InvalidCrossChainMessage);
+
+    Ok(value)
+}
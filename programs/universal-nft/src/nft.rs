@@ -0,0 +1,783 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+use anchor_spl::associated_token::{self, get_associated_token_address, Create};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_spl::token::{self, spl_token, InitializeMint2, MintTo};
+use mpl_token_metadata::accounts::MasterEdition;
+use mpl_token_metadata::instructions::{
+    CreateMasterEditionV3, CreateMasterEditionV3InstructionArgs, CreateMetadataAccountV3,
+    CreateMetadataAccountV3InstructionArgs, FreezeDelegatedAccount, ThawDelegatedAccount,
+};
+use mpl_token_metadata::types::{Creator, DataV2};
+use crate::state::*;
+use crate::errors::*;
+use crate::instructions::CrossChainReceiveEvent;
+
+/// Seed prefix of the mint PDA of a universal token ID
+pub const MINT_SEED: &[u8] = b"mint";
+
+/// Address of the mint holding the NFT with `token_id` on Solana.
+///
+/// Mints are PDAs of `[b"mint", token_id]`, so anyone can locate a universal
+/// NFT without an index, and the program can create the mint without a
+/// keypair signature.
+pub fn find_mint_address(token_id: &[u8; 32], program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MINT_SEED, token_id.as_ref()], program_id)
+}
+
+/// How an outbound transfer takes an NFT off Solana
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NftCustody {
+    /// Burned, and minted again by the mint authority PDA when it comes back
+    Burn,
+    /// Frozen in the owner's token account with the mint authority PDA as
+    /// delegate, and thawed when it comes back
+    Freeze,
+}
+
+impl NftCustody {
+    /// NFTs first minted on Solana have a master edition holding their mint
+    /// authority, so they cannot be minted again and are frozen instead
+    pub fn of(nft_origin: &NftOrigin) -> Self {
+        if nft_origin.origin_chain_id == SOLANA_CHAIN_ID {
+            NftCustody::Freeze
+        } else {
+            NftCustody::Burn
+        }
+    }
+
+    /// Supply of the mint while the NFT is on another chain
+    pub fn away_supply(self) -> u64 {
+        match self {
+            NftCustody::Burn => 0,
+            NftCustody::Freeze => 1,
+        }
+    }
+}
+
+/// Accounts needed to issue an NFT arriving from another chain.
+///
+/// `payer` is either a program PDA signed with `payer_seeds`, so an inbound
+/// call can pay for the new accounts without any signer besides the gateway,
+/// or a transaction signer when `payer_seeds` is `None`. A Solana-native NFT
+/// returning to Solana also needs its master edition and the token account
+/// it was frozen in.
+pub struct InboundNftAccounts<'a, 'info> {
+    pub program_id: &'a Pubkey,
+    pub payer: AccountInfo<'info>,
+    pub payer_seeds: Option<&'a [&'a [u8]]>,
+    pub mint: AccountInfo<'info>,
+    pub token_account: AccountInfo<'info>,
+    pub nft_origin: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub mint_authority: AccountInfo<'info>,
+    pub mint_authority_bump: u8,
+    pub recipient: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+    pub token_metadata_program: AccountInfo<'info>,
+    pub master_edition: Option<AccountInfo<'info>>,
+    pub locked_token_account: Option<AccountInfo<'info>>,
+}
+
+/// Mints the NFT described by an inbound message to its recipient.
+///
+/// The mint, metadata, token account and origin record are created on first
+/// arrival. An NFT returning to Solana reuses its mint and origin record, and
+/// a Solana-native one is thawed and moved to the recipient instead of being
+/// minted. Returns whether the NFT was returning.
+pub fn issue_inbound_nft(accounts: &InboundNftAccounts, message: &CrossChainMessage) -> Result<bool> {
+    let token_id = message.token_id;
+
+    let (mint_address, mint_bump) = find_mint_address(&token_id, accounts.program_id);
+    require_keys_eq!(accounts.mint.key(), mint_address, UniversalNftError::InvalidMintAddress);
+    require_keys_eq!(
+        accounts.recipient.key(),
+        Pubkey::new_from_array(message.recipient),
+        UniversalNftError::InvalidRecipient
+    );
+
+    let (origin_address, origin_bump) = Pubkey::find_program_address(
+        &[b"nft_origin", mint_address.as_ref()],
+        accounts.program_id,
+    );
+    require_keys_eq!(accounts.nft_origin.key(), origin_address, UniversalNftError::AccountValidationFailed);
+    require_keys_eq!(
+        accounts.token_account.key(),
+        get_associated_token_address(&accounts.recipient.key(), &mint_address),
+        UniversalNftError::AccountValidationFailed
+    );
+
+    let mint_authority_seeds: &[&[u8]] = &[b"mint_authority", &[accounts.mint_authority_bump]];
+
+    let is_returning_nft = !is_empty(&accounts.mint);
+    let mut custody = NftCustody::Burn;
+    if is_returning_nft {
+        require_keys_eq!(*accounts.mint.owner, token::ID, UniversalNftError::InvalidMintAddress);
+        let mint = token::Mint::try_deserialize(&mut &accounts.mint.try_borrow_data()?[..])?;
+        let nft_origin = load_origin(accounts, &token_id)?;
+        check_nft_returning(mint.supply, &nft_origin)?;
+        custody = NftCustody::of(&nft_origin);
+        msg!("✅ NFT returning to Solana, reusing mint {}", mint_address);
+    } else {
+        // Solana-native NFTs are created with a master edition by `issue_native_nft`
+        require!(
+            message.origin_chain_id != SOLANA_CHAIN_ID,
+            UniversalNftError::InvalidCrossChainMessage
+        );
+        msg!("✅ NFT first time on Solana, creating mint {}", mint_address);
+
+        let mint_seeds: &[&[u8]] = &[MINT_SEED, token_id.as_ref(), &[mint_bump]];
+        create_pda_account(
+            accounts,
+            &accounts.mint,
+            mint_seeds,
+            token::Mint::LEN,
+            &token::ID,
+        )?;
+        token::initialize_mint2(
+            CpiContext::new(
+                accounts.token_program.clone(),
+                InitializeMint2 {
+                    mint: accounts.mint.clone(),
+                },
+            ),
+            0,
+            &accounts.mint_authority.key(),
+            Some(&accounts.mint_authority.key()),
+        )?;
+
+        create_metadata(accounts, inbound_metadata(message), mint_authority_seeds)?;
+    }
+
+    if is_empty(&accounts.token_account) {
+        msg!("Creating token account for {}", accounts.recipient.key());
+        associated_token::create(CpiContext::new_with_signer(
+            accounts.associated_token_program.clone(),
+            Create {
+                payer: accounts.payer.clone(),
+                associated_token: accounts.token_account.clone(),
+                authority: accounts.recipient.clone(),
+                mint: accounts.mint.clone(),
+                system_program: accounts.system_program.clone(),
+                token_program: accounts.token_program.clone(),
+            },
+            &signer_seeds(accounts.payer_seeds, &[]),
+        ))?;
+    }
+
+    match custody {
+        NftCustody::Burn => {
+            msg!("Minting token to recipient...");
+            token::mint_to(
+                CpiContext::new_with_signer(
+                    accounts.token_program.clone(),
+                    MintTo {
+                        mint: accounts.mint.clone(),
+                        to: accounts.token_account.clone(),
+                        authority: accounts.mint_authority.clone(),
+                    },
+                    &[mint_authority_seeds],
+                ),
+                1,
+            )
+            .map_err(|e| {
+                msg!("❌ Token mint failed: {:?}", e);
+                UniversalNftError::TokenMintFailed
+            })?;
+        }
+        NftCustody::Freeze => {
+            let (Some(master_edition), Some(locked_token_account)) =
+                (&accounts.master_edition, &accounts.locked_token_account)
+            else {
+                return Err(UniversalNftError::NftNotLocked.into());
+            };
+            release_locked_nft(
+                &NftLockAccounts {
+                    mint: accounts.mint.clone(),
+                    token_account: locked_token_account.clone(),
+                    master_edition: master_edition.clone(),
+                    mint_authority: accounts.mint_authority.clone(),
+                    mint_authority_bump: accounts.mint_authority_bump,
+                    token_program: accounts.token_program.clone(),
+                    token_metadata_program: accounts.token_metadata_program.clone(),
+                },
+                &accounts.token_account,
+            )?
+        }
+    }
+
+    let nft_origin = record_origin(accounts, message, origin_bump)?;
+
+    emit!(CrossChainReceiveEvent {
+        token_id,
+        mint: mint_address,
+        recipient: accounts.recipient.key(),
+        is_returning: is_returning_nft,
+        source_chain_id: nft_origin.origin_chain_id,
+        transfer_count: nft_origin.transfer_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(is_returning_nft)
+}
+
+/// Checks that an NFT arriving for an existing mint is away from Solana, so
+/// a repeated or replayed message cannot mint a second copy of it
+pub fn check_nft_returning(mint_supply: u64, nft_origin: &NftOrigin) -> Result<()> {
+    require!(
+        mint_supply == NftCustody::of(nft_origin).away_supply()
+            && nft_origin.current_chain_id != SOLANA_CHAIN_ID,
+        UniversalNftError::NftAlreadyOnSolana
+    );
+    Ok(())
+}
+
+/// Thaws a returning Solana-native NFT and moves it from the token account
+/// it was frozen in to the recipient's `token_account`
+pub(crate) fn release_locked_nft<'info>(lock: &NftLockAccounts<'info>, token_account: &AccountInfo<'info>) -> Result<()> {
+    thaw_nft(lock)?;
+
+    if lock.token_account.key() != token_account.key() {
+        msg!("Moving NFT from {} to recipient", lock.token_account.key());
+        let mint_authority_seeds: &[&[u8]] = &[b"mint_authority", &[lock.mint_authority_bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                lock.token_program.clone(),
+                token::Transfer {
+                    from: lock.token_account.clone(),
+                    to: token_account.clone(),
+                    authority: lock.mint_authority.clone(),
+                },
+                &[mint_authority_seeds],
+            ),
+            1,
+        )?;
+    }
+    Ok(())
+}
+
+/// Accounts needed to freeze or thaw a Solana-native NFT in its holder's
+/// token account
+pub struct NftLockAccounts<'info> {
+    pub mint: AccountInfo<'info>,
+    pub token_account: AccountInfo<'info>,
+    pub master_edition: AccountInfo<'info>,
+    pub mint_authority: AccountInfo<'info>,
+    pub mint_authority_bump: u8,
+    pub token_program: AccountInfo<'info>,
+    pub token_metadata_program: AccountInfo<'info>,
+}
+
+/// Metaplex instruction freezing a token account delegated to the mint
+/// authority PDA, signed for by the master edition of `mint`
+pub fn freeze_nft_instruction(mint: &Pubkey, token_account: &Pubkey, mint_authority: &Pubkey) -> Instruction {
+    FreezeDelegatedAccount {
+        delegate: *mint_authority,
+        token_account: *token_account,
+        edition: MasterEdition::find_pda(mint).0,
+        mint: *mint,
+        token_program: token::ID,
+    }
+    .instruction()
+}
+
+/// Metaplex instruction thawing a token account frozen by `freeze_nft_instruction`
+pub fn thaw_nft_instruction(mint: &Pubkey, token_account: &Pubkey, mint_authority: &Pubkey) -> Instruction {
+    ThawDelegatedAccount {
+        delegate: *mint_authority,
+        token_account: *token_account,
+        edition: MasterEdition::find_pda(mint).0,
+        mint: *mint,
+        token_program: token::ID,
+    }
+    .instruction()
+}
+
+/// Checks that a token account holds the NFT of `mint` frozen by an
+/// outbound transfer
+pub fn check_locked_nft(token_account: &spl_token::state::Account, mint: &Pubkey, mint_authority: &Pubkey) -> Result<()> {
+    require!(
+        token_account.mint == *mint
+            && token_account.amount == 1
+            && token_account.is_frozen()
+            && token_account.delegate == Some(*mint_authority).into(),
+        UniversalNftError::NftNotLocked
+    );
+    Ok(())
+}
+
+/// Freezes a Solana-native NFT leaving Solana. The owner must have approved
+/// the mint authority PDA as delegate of its token account.
+pub fn freeze_nft(accounts: &NftLockAccounts) -> Result<()> {
+    let instruction = freeze_nft_instruction(
+        &accounts.mint.key(),
+        &accounts.token_account.key(),
+        &accounts.mint_authority.key(),
+    );
+    invoke_lock_instruction(accounts, &instruction)?;
+
+    msg!("✅ NFT frozen in {}", accounts.token_account.key());
+    Ok(())
+}
+
+/// Thaws a Solana-native NFT frozen by `freeze_nft`. The mint authority PDA
+/// stays delegate until the holder revokes it or it moves the NFT.
+pub fn thaw_nft(accounts: &NftLockAccounts) -> Result<()> {
+    require_keys_eq!(*accounts.token_account.owner, token::ID, UniversalNftError::NftNotLocked);
+    let token_account = spl_token::state::Account::unpack(&accounts.token_account.try_borrow_data()?)?;
+    check_locked_nft(&token_account, &accounts.mint.key(), &accounts.mint_authority.key())?;
+
+    let instruction = thaw_nft_instruction(
+        &accounts.mint.key(),
+        &accounts.token_account.key(),
+        &accounts.mint_authority.key(),
+    );
+    invoke_lock_instruction(accounts, &instruction)?;
+
+    msg!("✅ NFT thawed in {}", accounts.token_account.key());
+    Ok(())
+}
+
+fn invoke_lock_instruction(accounts: &NftLockAccounts, instruction: &Instruction) -> Result<()> {
+    require_keys_eq!(
+        accounts.master_edition.key(),
+        MasterEdition::find_pda(&accounts.mint.key()).0,
+        UniversalNftError::AccountValidationFailed
+    );
+
+    let mint_authority_seeds: &[&[u8]] = &[b"mint_authority", &[accounts.mint_authority_bump]];
+    anchor_lang::solana_program::program::invoke_signed(
+        instruction,
+        &[
+            accounts.mint_authority.clone(),
+            accounts.token_account.clone(),
+            accounts.master_edition.clone(),
+            accounts.mint.clone(),
+            accounts.token_program.clone(),
+            accounts.token_metadata_program.clone(),
+        ],
+        &[mint_authority_seeds],
+    )?;
+    Ok(())
+}
+
+/// Reads the origin record of an existing mint
+fn load_origin(accounts: &InboundNftAccounts, token_id: &[u8; 32]) -> Result<NftOrigin> {
+    require!(!is_empty(&accounts.nft_origin), UniversalNftError::AccountValidationFailed);
+    require_keys_eq!(*accounts.nft_origin.owner, *accounts.program_id, UniversalNftError::AccountValidationFailed);
+    let nft_origin = NftOrigin::try_deserialize(&mut &accounts.nft_origin.try_borrow_data()?[..])?;
+    require!(nft_origin.token_id == *token_id, UniversalNftError::InvalidTokenId);
+    Ok(nft_origin)
+}
+
+/// Creates and mints a new Solana-native NFT with a master edition.
+///
+/// Unlike `issue_inbound_nft`, the mint must not exist yet, and the origin
+/// record starts on Solana with no transfers. Returns the mint address.
+pub fn issue_native_nft<'info>(
+    accounts: &InboundNftAccounts<'_, 'info>,
+    master_edition: &AccountInfo<'info>,
+    token_id: &[u8; 32],
+    data: DataV2,
+) -> Result<Pubkey> {
+    let (mint_address, mint_bump) = find_mint_address(token_id, accounts.program_id);
+    require_keys_eq!(accounts.mint.key(), mint_address, UniversalNftError::InvalidMintAddress);
+    require!(is_empty(&accounts.mint), UniversalNftError::InvalidMintAddress);
+
+    let (origin_address, origin_bump) = Pubkey::find_program_address(
+        &[b"nft_origin", mint_address.as_ref()],
+        accounts.program_id,
+    );
+    require_keys_eq!(accounts.nft_origin.key(), origin_address, UniversalNftError::AccountValidationFailed);
+    require_keys_eq!(
+        accounts.token_account.key(),
+        get_associated_token_address(&accounts.recipient.key(), &mint_address),
+        UniversalNftError::AccountValidationFailed
+    );
+
+    let mint_authority_seeds: &[&[u8]] = &[b"mint_authority", &[accounts.mint_authority_bump]];
+
+    let mint_seeds: &[&[u8]] = &[MINT_SEED, token_id.as_ref(), &[mint_bump]];
+    create_pda_account(
+        accounts,
+        &accounts.mint,
+        mint_seeds,
+        token::Mint::LEN,
+        &token::ID,
+    )?;
+    token::initialize_mint2(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            InitializeMint2 {
+                mint: accounts.mint.clone(),
+            },
+        ),
+        0,
+        &accounts.mint_authority.key(),
+        Some(&accounts.mint_authority.key()),
+    )?;
+
+    associated_token::create(CpiContext::new_with_signer(
+        accounts.associated_token_program.clone(),
+        Create {
+            payer: accounts.payer.clone(),
+            associated_token: accounts.token_account.clone(),
+            authority: accounts.recipient.clone(),
+            mint: accounts.mint.clone(),
+            system_program: accounts.system_program.clone(),
+            token_program: accounts.token_program.clone(),
+        },
+        &signer_seeds(accounts.payer_seeds, &[]),
+    ))?;
+
+    // The master edition takes over the mint authority, so mint first
+    token::mint_to(
+        CpiContext::new_with_signer(
+            accounts.token_program.clone(),
+            MintTo {
+                mint: accounts.mint.clone(),
+                to: accounts.token_account.clone(),
+                authority: accounts.mint_authority.clone(),
+            },
+            &[mint_authority_seeds],
+        ),
+        1,
+    )
+    .map_err(|e| {
+        msg!("❌ Token mint failed: {:?}", e);
+        UniversalNftError::TokenMintFailed
+    })?;
+
+    create_metadata(accounts, data, mint_authority_seeds)?;
+
+    let create_master_edition_ix = CreateMasterEditionV3 {
+        edition: master_edition.key(),
+        mint: mint_address,
+        update_authority: accounts.mint_authority.key(),
+        mint_authority: accounts.mint_authority.key(),
+        payer: accounts.payer.key(),
+        metadata: accounts.metadata.key(),
+        token_program: accounts.token_program.key(),
+        system_program: accounts.system_program.key(),
+        rent: Some(accounts.rent.key()),
+    }
+    .instruction(CreateMasterEditionV3InstructionArgs {
+        max_supply: Some(0),
+    });
+
+    anchor_lang::solana_program::program::invoke_signed(
+        &create_master_edition_ix,
+        &[
+            master_edition.clone(),
+            accounts.mint.clone(),
+            accounts.mint_authority.clone(),
+            accounts.payer.clone(),
+            accounts.metadata.clone(),
+            accounts.token_program.clone(),
+            accounts.system_program.clone(),
+            accounts.rent.clone(),
+            accounts.token_metadata_program.clone(),
+        ],
+        &signer_seeds(accounts.payer_seeds, &[mint_authority_seeds]),
+    )?;
+
+    let clock = Clock::get()?;
+    let origin_seeds: &[&[u8]] = &[b"nft_origin", mint_address.as_ref(), &[origin_bump]];
+    create_pda_account(
+        accounts,
+        &accounts.nft_origin,
+        origin_seeds,
+        8 + NftOrigin::INIT_SPACE,
+        accounts.program_id,
+    )?;
+    NftOrigin {
+        original_mint: mint_address,
+        token_id: *token_id,
+        origin_chain_id: SOLANA_CHAIN_ID,
+        block_number: clock.slot,
+        current_chain_id: SOLANA_CHAIN_ID,
+        transfer_count: 0,
+        last_transfer_timestamp: clock.unix_timestamp,
+        bump: origin_bump,
+    }
+    .try_serialize(&mut &mut accounts.nft_origin.try_borrow_mut_data()?[..])?;
+
+    Ok(mint_address)
+}
+
+/// Signer seeds for a CPI paid by the payer, followed by `other_seeds`
+fn signer_seeds<'s>(payer_seeds: Option<&'s [&'s [u8]]>, other_seeds: &[&'s [&'s [u8]]]) -> Vec<&'s [&'s [u8]]> {
+    payer_seeds.into_iter().chain(other_seeds.iter().copied()).collect()
+}
+
+fn is_empty(account: &AccountInfo) -> bool {
+    account.data_is_empty() && *account.owner == system_program::ID
+}
+
+/// Creates a program-derived account funded by the payer PDA.
+///
+/// An account that was already sent lamports cannot go through
+/// `create_account`, so it is topped up, allocated and assigned instead.
+pub(crate) fn create_pda_account<'info>(
+    accounts: &InboundNftAccounts<'_, 'info>,
+    account: &AccountInfo<'info>,
+    account_seeds: &[&[u8]],
+    space: usize,
+    owner: &Pubkey,
+) -> Result<()> {
+    let required_lamports = Rent::get()?.minimum_balance(space);
+    let current_lamports = account.lamports();
+
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                accounts.system_program.clone(),
+                CreateAccount {
+                    from: accounts.payer.clone(),
+                    to: account.clone(),
+                },
+                &signer_seeds(accounts.payer_seeds, &[account_seeds]),
+            ),
+            required_lamports,
+            space as u64,
+            owner,
+        );
+    }
+
+    if required_lamports > current_lamports {
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                accounts.system_program.clone(),
+                Transfer {
+                    from: accounts.payer.clone(),
+                    to: account.clone(),
+                },
+                &signer_seeds(accounts.payer_seeds, &[]),
+            ),
+            required_lamports - current_lamports,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            accounts.system_program.clone(),
+            Allocate {
+                account_to_allocate: account.clone(),
+            },
+            &[account_seeds],
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            accounts.system_program.clone(),
+            Assign {
+                account_to_assign: account.clone(),
+            },
+            &[account_seeds],
+        ),
+        owner,
+    )
+}
+
+/// Metadata of an NFT arriving with `message`
+pub(crate) fn inbound_metadata(message: &CrossChainMessage) -> DataV2 {
+    // Creators cannot sign from another chain, so none are marked verified
+    let creators = message.metadata.creators.as_ref().map(|creators| {
+        creators
+            .iter()
+            .map(|creator| Creator {
+                address: Pubkey::new_from_array(creator.address),
+                verified: false,
+                share: creator.share,
+            })
+            .collect()
+    });
+
+    DataV2 {
+        name: message.metadata.name.clone(),
+        symbol: message.metadata.symbol.clone(),
+        uri: message.metadata.uri.clone(),
+        seller_fee_basis_points: message.metadata.seller_fee_basis_points,
+        creators,
+        collection: None,
+        uses: None,
+    }
+}
+
+fn create_metadata(
+    accounts: &InboundNftAccounts,
+    data: DataV2,
+    mint_authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let create_metadata_ix = CreateMetadataAccountV3 {
+        metadata: accounts.metadata.key(),
+        mint: accounts.mint.key(),
+        mint_authority: accounts.mint_authority.key(),
+        payer: accounts.payer.key(),
+        update_authority: (accounts.mint_authority.key(), true),
+        system_program: accounts.system_program.key(),
+        rent: Some(accounts.rent.key()),
+    }
+    .instruction(CreateMetadataAccountV3InstructionArgs {
+        data,
+        is_mutable: true,
+        collection_details: None,
+    });
+
+    msg!("Creating metadata account...");
+    anchor_lang::solana_program::program::invoke_signed(
+        &create_metadata_ix,
+        &[
+            accounts.metadata.clone(),
+            accounts.mint.clone(),
+            accounts.mint_authority.clone(),
+            accounts.payer.clone(),
+            accounts.system_program.clone(),
+            accounts.rent.clone(),
+            accounts.token_metadata_program.clone(),
+        ],
+        &signer_seeds(accounts.payer_seeds, &[mint_authority_seeds]),
+    )
+    .map_err(|e| {
+        msg!("❌ Metadata creation failed: {:?}", e);
+        UniversalNftError::MetadataCreationFailed.into()
+    })
+}
+
+/// Creates or updates the origin record of the NFT
+fn record_origin(
+    accounts: &InboundNftAccounts,
+    message: &CrossChainMessage,
+    origin_bump: u8,
+) -> Result<NftOrigin> {
+    let clock = Clock::get()?;
+
+    let nft_origin = if is_empty(&accounts.nft_origin) {
+        let origin_seeds: &[&[u8]] = &[b"nft_origin", accounts.mint.key.as_ref(), &[origin_bump]];
+        create_pda_account(
+            accounts,
+            &accounts.nft_origin,
+            origin_seeds,
+            8 + NftOrigin::INIT_SPACE,
+            accounts.program_id,
+        )?;
+
+        NftOrigin {
+            original_mint: accounts.mint.key(),
+            token_id: message.token_id,
+            origin_chain_id: message.origin_chain_id,
+            block_number: clock.slot,
+            current_chain_id: SOLANA_CHAIN_ID,
+            transfer_count: 1,
+            last_transfer_timestamp: clock.unix_timestamp,
+            bump: origin_bump,
+        }
+    } else {
+        let mut nft_origin = load_origin(accounts, &message.token_id)?;
+
+        nft_origin.current_chain_id = SOLANA_CHAIN_ID;
+        nft_origin.transfer_count += 1;
+        nft_origin.last_transfer_timestamp = clock.unix_timestamp;
+        nft_origin
+    };
+
+    nft_origin.try_serialize(&mut &mut accounts.nft_origin.try_borrow_mut_data()?[..])?;
+
+    msg!("NFT origin updated:");
+    msg!("  Origin chain: {}", nft_origin.origin_chain_id);
+    msg!("  Current chain: {}", nft_origin.current_chain_id);
+    msg!("  Transfer count: {}", nft_origin.transfer_count);
+
+    Ok(nft_origin)
+}
+
+/// Accounts needed to give an NFT back to its owner after a failed transfer.
+/// `master_edition` and `token_metadata_program` are needed for
+/// Solana-native NFTs, which were frozen instead of burned.
+pub struct RestoreNftAccounts<'a, 'info> {
+    pub payer: AccountInfo<'info>,
+    pub payer_seeds: Option<&'a [&'a [u8]]>,
+    pub mint: AccountInfo<'info>,
+    pub owner: AccountInfo<'info>,
+    pub owner_token_account: AccountInfo<'info>,
+    pub mint_authority: AccountInfo<'info>,
+    pub mint_authority_bump: u8,
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub master_edition: Option<AccountInfo<'info>>,
+    pub token_metadata_program: Option<AccountInfo<'info>>,
+}
+
+/// Gives an NFT taken off Solana by an outbound transfer back to its owner.
+///
+/// A burned NFT is minted again, recreating the owner's token account if it
+/// was closed in the meantime. A frozen one is thawed where it is.
+pub fn restore_nft(accounts: &RestoreNftAccounts, custody: NftCustody) -> Result<()> {
+    require_keys_eq!(
+        accounts.owner_token_account.key(),
+        get_associated_token_address(&accounts.owner.key(), &accounts.mint.key()),
+        UniversalNftError::AccountValidationFailed
+    );
+
+    if custody == NftCustody::Freeze {
+        let (Some(master_edition), Some(token_metadata_program)) =
+            (&accounts.master_edition, &accounts.token_metadata_program)
+        else {
+            return Err(UniversalNftError::NftNotLocked.into());
+        };
+        thaw_nft(&NftLockAccounts {
+            mint: accounts.mint.clone(),
+            token_account: accounts.owner_token_account.clone(),
+            master_edition: master_edition.clone(),
+            mint_authority: accounts.mint_authority.clone(),
+            mint_authority_bump: accounts.mint_authority_bump,
+            token_program: accounts.token_program.clone(),
+            token_metadata_program: token_metadata_program.clone(),
+        })?;
+
+        msg!("✅ NFT restored to {}", accounts.owner.key());
+        return Ok(());
+    }
+
+    if accounts.owner_token_account.data_is_empty() {
+        msg!("Recreating token account of the original owner");
+        associated_token::create(CpiContext::new_with_signer(
+            accounts.associated_token_program.clone(),
+            Create {
+                payer: accounts.payer.clone(),
+                associated_token: accounts.owner_token_account.clone(),
+                authority: accounts.owner.clone(),
+                mint: accounts.mint.clone(),
+                system_program: accounts.system_program.clone(),
+                token_program: accounts.token_program.clone(),
+            },
+            &signer_seeds(accounts.payer_seeds, &[]),
+        ))?;
+    }
+
+    let mint_authority_seeds: &[&[u8]] = &[b"mint_authority", &[accounts.mint_authority_bump]];
+    token::mint_to(
+        CpiContext::new_with_signer(
+            accounts.token_program.clone(),
+            MintTo {
+                mint: accounts.mint.clone(),
+                to: accounts.owner_token_account.clone(),
+                authority: accounts.mint_authority.clone(),
+            },
+            &[mint_authority_seeds],
+        ),
+        1,
+    )
+    .map_err(|e| {
+        msg!("❌ Token re-mint failed: {:?}", e);
+        UniversalNftError::TokenMintFailed
+    })?;
+
+    msg!("✅ NFT restored to {}", accounts.owner.key());
+    Ok(())
+}
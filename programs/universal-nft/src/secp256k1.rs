@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::secp256k1_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use crate::errors::*;
+
+/// Size of one `SecpSignatureOffsets` entry in secp256k1 instruction data
+pub const SIGNATURE_OFFSETS_LEN: usize = 11;
+
+/// Size of an Ethereum address
+pub const ETH_ADDRESS_LEN: usize = 20;
+
+/// Checks that the instruction right before the current one is a secp256k1
+/// program instruction in which `eth_address` signed `message`.
+pub fn verify_preceding_secp256k1_signature(
+    instructions_sysvar: &AccountInfo,
+    eth_address: &[u8; 20],
+    message: &[u8],
+) -> Result<()> {
+    let (instruction, index) = load_preceding_instruction(instructions_sysvar)?;
+    verify_secp256k1_instruction(&instruction, index, eth_address, message)
+}
+
+/// Loads the instruction right before the current one, with its index
+pub fn load_preceding_instruction(instructions_sysvar: &AccountInfo) -> Result<(Instruction, u16)> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, UniversalNftError::InvalidSignatureInstruction);
+
+    let index = current_index - 1;
+    let instruction = load_instruction_at_checked(index as usize, instructions_sysvar)?;
+    Ok((instruction, index))
+}
+
+/// Checks what a secp256k1 program instruction at `instruction_index` proves.
+///
+/// The runtime has already verified the signature by the time this program
+/// runs, so only the signed address and message are compared. The instruction
+/// must carry exactly one signature, and every offset must point into its own
+/// data so the proof cannot borrow bytes from another instruction.
+pub fn verify_secp256k1_instruction(
+    instruction: &Instruction,
+    instruction_index: u16,
+    eth_address: &[u8; 20],
+    message: &[u8],
+) -> Result<()> {
+    require_keys_eq!(
+        instruction.program_id,
+        secp256k1_program::ID,
+        UniversalNftError::InvalidSignatureInstruction
+    );
+
+    let data = &instruction.data;
+    require!(data.first() == Some(&1), UniversalNftError::InvalidSignatureInstruction);
+    let offsets = data
+        .get(1..1 + SIGNATURE_OFFSETS_LEN)
+        .ok_or(UniversalNftError::InvalidSignatureInstruction)?;
+    let read_u16 = |at: usize| u16::from_le_bytes([offsets[at], offsets[at + 1]]) as usize;
+    let signature_instruction_index = offsets[2];
+    let eth_address_offset = read_u16(3);
+    let eth_address_instruction_index = offsets[5];
+    let message_offset = read_u16(6);
+    let message_size = read_u16(8);
+    let message_instruction_index = offsets[10];
+
+    require!(
+        [
+            signature_instruction_index,
+            eth_address_instruction_index,
+            message_instruction_index,
+        ]
+        .iter()
+        .all(|index| *index as u16 == instruction_index),
+        UniversalNftError::InvalidSignatureInstruction
+    );
+
+    let signed_address = data
+        .get(eth_address_offset..eth_address_offset + ETH_ADDRESS_LEN)
+        .ok_or(UniversalNftError::InvalidSignatureInstruction)?;
+    let signed_message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(UniversalNftError::InvalidSignatureInstruction)?;
+
+    require!(signed_address == eth_address, UniversalNftError::SignatureMismatch);
+    require!(signed_message == message, UniversalNftError::SignatureMismatch);
+
+    Ok(())
+}
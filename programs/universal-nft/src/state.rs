@@ -3,8 +3,21 @@ use anchor_lang::prelude::*;
 /// Solana chain ID for cross-chain operations
 pub const SOLANA_CHAIN_ID: u64 = 101; // Solana mainnet chain ID
 
+/// ZetaChain chain ID. Gateway calls and TSS-signed messages are always sent
+/// from ZetaChain, so its chain config decodes every inbound message. Builds
+/// with the `mainnet` feature use ZetaChain mainnet, others Athens testnet.
+#[cfg(feature = "mainnet")]
+pub const ZETACHAIN_CHAIN_ID: u64 = 7000;
+#[cfg(not(feature = "mainnet"))]
+pub const ZETACHAIN_CHAIN_ID: u64 = 7001;
+
+/// Layout version of `ProgramState` written by this program, raised by
+/// `migrate_state`
+pub const PROGRAM_STATE_VERSION: u8 = 2;
+
 /// Program state account
 #[account]
+#[derive(InitSpace)]
 pub struct ProgramState {
     /// Authority that can update the program
     pub authority: Pubkey,
@@ -22,14 +35,595 @@ pub struct ProgramState {
     pub version: u8,
     /// Bump seed for PDA
     pub bump: u8,
+    /// Ethereum address of the ZetaChain TSS key authorizing `execute_signed`,
+    /// zero while the signed path is disabled
+    pub tss_address: [u8; 20],
+    /// Nonce the next `execute_signed` call must carry
+    pub tss_nonce: u64,
+    /// Key allowed to pause and unpause besides the authority, zero if none
+    pub pauser: Pubkey,
+    /// Paused operations, a combination of the `PAUSE_*` bits
+    pub paused: u8,
+    /// Gateway queued by `queue_gateway_update`, zero if none is queued
+    pub pending_gateway: Pubkey,
+    /// Earliest time the queued gateway can be applied
+    pub gateway_update_eta: i64,
+    /// Seconds between queueing a gateway update and applying it
+    pub gateway_update_delay: i64,
+    /// Authority proposed by `propose_authority`, zero if none is pending
+    pub pending_authority: Pubkey,
+    /// Shorter gateway update delay queued by `set_gateway_update_delay`,
+    /// zero if none is queued
+    pub pending_gateway_update_delay: i64,
+    /// TSS address queued by `queue_tss_address_update`, zero if none is queued
+    pub pending_tss_address: [u8; 20],
+    /// Earliest time the queued TSS address can be applied
+    pub tss_update_eta: i64,
+    /// Pauser queued by `queue_pauser_update`, zero to remove the pauser
+    pub pending_pauser: Pubkey,
+    /// Earliest time the queued pauser can be applied, zero if none is queued
+    pub pauser_update_eta: i64,
 }
 
+/// Gateway update delay of a newly initialized program
+pub const DEFAULT_GATEWAY_UPDATE_DELAY: i64 = 2 * 24 * 60 * 60;
+/// Shortest gateway update delay the authority can configure
+pub const MIN_GATEWAY_UPDATE_DELAY: i64 = 60 * 60;
+
+/// Pause bit stopping `mint_nft`
+pub const PAUSE_MINT: u8 = 1 << 0;
+/// Pause bit stopping NFTs from leaving Solana
+pub const PAUSE_OUTBOUND: u8 = 1 << 1;
+/// Pause bit stopping inbound gateway calls and signed execution. Reverts and
+/// aborts of outbound transfers are not stopped, so NFTs sent from Solana can
+/// always come back to their owners.
+pub const PAUSE_INBOUND: u8 = 1 << 2;
+/// All pause bits
+pub const PAUSE_ALL: u8 = PAUSE_MINT | PAUSE_OUTBOUND | PAUSE_INBOUND;
+
 impl ProgramState {
-    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 1 + 1;
+    /// Fails if any of the `scope` pause bits is set
+    pub fn require_not_paused(&self, scope: u8) -> Result<()> {
+        if self.paused & scope != 0 {
+            msg!("Operation paused, paused bits: {:#05b}", self.paused);
+            return Err(crate::errors::UniversalNftError::ProgramPaused.into());
+        }
+        Ok(())
+    }
+    
+    /// Whether `key` may pause or unpause the program
+    pub fn can_pause(&self, key: &Pubkey) -> bool {
+        *key == self.authority || (self.pauser != Pubkey::default() && *key == self.pauser)
+    }
+    
+    /// Whether a gateway or update delay change is waiting in the queue.
+    /// Only one change can be queued at a time.
+    pub fn has_queued_update(&self) -> bool {
+        self.pending_gateway != Pubkey::default() || self.pending_gateway_update_delay != 0
+    }
+    
+    /// Queues `new_gateway` behind the update delay. Returns its ETA.
+    pub fn queue_gateway(&mut self, new_gateway: Pubkey, now: i64) -> Result<i64> {
+        require!(new_gateway != Pubkey::default(), crate::errors::UniversalNftError::InvalidGateway);
+        require!(!self.has_queued_update(), crate::errors::UniversalNftError::GatewayUpdateAlreadyQueued);
+        
+        self.pending_gateway = new_gateway;
+        self.gateway_update_eta = now + self.gateway_update_delay;
+        Ok(self.gateway_update_eta)
+    }
+    
+    /// Applies a longer update delay at once, or queues a shorter one behind
+    /// the current delay so lowering it cannot speed up a gateway change.
+    /// Returns the ETA of a queued delay.
+    pub fn change_gateway_update_delay(&mut self, delay: i64, now: i64) -> Result<Option<i64>> {
+        require!(
+            delay >= MIN_GATEWAY_UPDATE_DELAY,
+            crate::errors::UniversalNftError::GatewayUpdateDelayTooShort
+        );
+        
+        if delay >= self.gateway_update_delay {
+            self.gateway_update_delay = delay;
+            return Ok(None);
+        }
+        
+        require!(!self.has_queued_update(), crate::errors::UniversalNftError::GatewayUpdateAlreadyQueued);
+        self.pending_gateway_update_delay = delay;
+        self.gateway_update_eta = now + self.gateway_update_delay;
+        Ok(Some(self.gateway_update_eta))
+    }
+    
+    /// Applies the queued gateway or update delay once its ETA has passed
+    pub fn execute_queued_update(&mut self, now: i64) -> Result<()> {
+        require!(self.has_queued_update(), crate::errors::UniversalNftError::NoGatewayUpdateQueued);
+        require!(
+            now >= self.gateway_update_eta,
+            crate::errors::UniversalNftError::GatewayUpdateNotReady
+        );
+        
+        if self.pending_gateway != Pubkey::default() {
+            self.gateway = self.pending_gateway;
+        } else {
+            self.gateway_update_delay = self.pending_gateway_update_delay;
+        }
+        self.clear_queued_update();
+        Ok(())
+    }
+    
+    /// Drops the queued gateway or update delay
+    pub fn cancel_queued_update(&mut self) -> Result<()> {
+        require!(self.has_queued_update(), crate::errors::UniversalNftError::NoGatewayUpdateQueued);
+        self.clear_queued_update();
+        Ok(())
+    }
+    
+    fn clear_queued_update(&mut self) {
+        self.pending_gateway = Pubkey::default();
+        self.pending_gateway_update_delay = 0;
+        self.gateway_update_eta = 0;
+    }
+    
+    /// Whether a TSS address rotation is waiting in the queue
+    pub fn has_queued_tss_update(&self) -> bool {
+        self.pending_tss_address != [0u8; 20]
+    }
+    
+    /// Queues `tss_address` behind the gateway update delay, as it can
+    /// authorize inbound messages just like the gateway. A zero address
+    /// disables `execute_signed` at once instead, since it only takes power
+    /// away. Returns the ETA of a queued address.
+    pub fn queue_tss_address(&mut self, tss_address: [u8; 20], now: i64) -> Result<Option<i64>> {
+        if tss_address == [0u8; 20] {
+            self.tss_address = tss_address;
+            return Ok(None);
+        }
+        
+        require!(!self.has_queued_tss_update(), crate::errors::UniversalNftError::TssUpdateAlreadyQueued);
+        self.pending_tss_address = tss_address;
+        self.tss_update_eta = now + self.gateway_update_delay;
+        Ok(Some(self.tss_update_eta))
+    }
+    
+    /// Applies the queued TSS address once its ETA has passed
+    pub fn execute_queued_tss_update(&mut self, now: i64) -> Result<()> {
+        require!(self.has_queued_tss_update(), crate::errors::UniversalNftError::NoTssUpdateQueued);
+        require!(now >= self.tss_update_eta, crate::errors::UniversalNftError::TssUpdateNotReady);
+        
+        self.tss_address = self.pending_tss_address;
+        self.clear_queued_tss_update();
+        Ok(())
+    }
+    
+    /// Drops the queued TSS address
+    pub fn cancel_queued_tss_update(&mut self) -> Result<()> {
+        require!(self.has_queued_tss_update(), crate::errors::UniversalNftError::NoTssUpdateQueued);
+        self.clear_queued_tss_update();
+        Ok(())
+    }
+    
+    fn clear_queued_tss_update(&mut self) {
+        self.pending_tss_address = [0u8; 20];
+        self.tss_update_eta = 0;
+    }
+    
+    /// Whether a pauser change is waiting in the queue
+    pub fn has_queued_pauser_update(&self) -> bool {
+        self.pauser_update_eta != 0
+    }
+    
+    /// Queues `pauser` behind the gateway update delay, so the current pauser
+    /// keeps its veto over queued gateway and TSS changes until then. Returns
+    /// its ETA.
+    pub fn queue_pauser(&mut self, pauser: Pubkey, now: i64) -> Result<i64> {
+        require!(!self.has_queued_pauser_update(), crate::errors::UniversalNftError::PauserUpdateAlreadyQueued);
+        
+        self.pending_pauser = pauser;
+        self.pauser_update_eta = now + self.gateway_update_delay;
+        Ok(self.pauser_update_eta)
+    }
+    
+    /// Applies the queued pauser once its ETA has passed
+    pub fn execute_queued_pauser_update(&mut self, now: i64) -> Result<()> {
+        require!(self.has_queued_pauser_update(), crate::errors::UniversalNftError::NoPauserUpdateQueued);
+        require!(now >= self.pauser_update_eta, crate::errors::UniversalNftError::PauserUpdateNotReady);
+        
+        self.pauser = self.pending_pauser;
+        self.clear_queued_pauser_update();
+        Ok(())
+    }
+    
+    /// Drops the queued pauser
+    pub fn cancel_queued_pauser_update(&mut self) -> Result<()> {
+        require!(self.has_queued_pauser_update(), crate::errors::UniversalNftError::NoPauserUpdateQueued);
+        self.clear_queued_pauser_update();
+        Ok(())
+    }
+    
+    fn clear_queued_pauser_update(&mut self) {
+        self.pending_pauser = Pubkey::default();
+        self.pauser_update_eta = 0;
+    }
+    
+    /// Universal token ID the next NFT minted on Solana will receive
+    pub fn next_universal_token_id(&self, program_id: Pubkey) -> [u8; 32] {
+        crate::token_id::derive_token_id(SOLANA_CHAIN_ID, &program_id, self.next_token_id)
+    }
+}
+
+/// Operational roles delegated by the authority. Pausing has its own key,
+/// `ProgramState::pauser`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    /// Manages protocol fees
+    FeeManager,
+    /// Restricts `mint_nft` to itself once set
+    Minter,
+    /// Configures connected chains
+    ChainManager,
+}
+
+/// Holders of the operational roles. The authority acts as admin and
+/// implicitly holds every role; a zero key leaves a role unassigned.
+#[account]
+#[derive(InitSpace)]
+pub struct Roles {
+    pub fee_manager: Pubkey,
+    pub minter: Pubkey,
+    pub chain_manager: Pubkey,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Roles {
+    /// Key holding `role`, zero if unassigned
+    pub fn holder(&self, role: Role) -> Pubkey {
+        match role {
+            Role::FeeManager => self.fee_manager,
+            Role::Minter => self.minter,
+            Role::ChainManager => self.chain_manager,
+        }
+    }
+    
+    pub fn set_holder(&mut self, role: Role, holder: Pubkey) {
+        match role {
+            Role::FeeManager => self.fee_manager = holder,
+            Role::Minter => self.minter = holder,
+            Role::ChainManager => self.chain_manager = holder,
+        }
+    }
+    
+    /// Whether `key` may act as `role`, either as its holder or as the authority
+    pub fn authorizes(&self, authority: &Pubkey, role: Role, key: &Pubkey) -> bool {
+        let holder = self.holder(role);
+        *key == *authority || (holder != Pubkey::default() && *key == holder)
+    }
+}
+
+/// Most payees a mint's proceeds can be split between
+pub const MAX_PROCEEDS_SHARES: usize = 5;
+
+/// Terms of minting through `mint_nft`. The default is free, unlimited and
+/// always open.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct MintSettings {
+    /// Price per NFT, in lamports or base units of `payment_mint`
+    pub price: u64,
+    /// SPL token the price is paid in, SOL if None. Requires a proceeds split
+    /// when priced, as the treasury only holds lamports.
+    pub payment_mint: Option<Pubkey>,
+    /// Most NFTs mintable under these settings, 0 for no cap
+    pub max_supply: u64,
+    /// Minting opens at this time, 0 for immediately
+    pub start_time: i64,
+    /// Minting closes at this time, 0 for never
+    pub end_time: i64,
+    /// Most NFTs one payer may mint, 0 for no limit
+    pub wallet_limit: u32,
+    /// Payees of the proceeds, the treasury if empty and paid in SOL
+    #[max_len(5)]
+    pub proceeds: Vec<ProceedsShare>,
+    /// Root of the allowlist tree, see `crate::merkle`; zero for public minting
+    pub merkle_root: [u8; 32],
+}
+
+/// Part of the mint price paid to one payee
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct ProceedsShare {
+    pub recipient: Pubkey,
+    /// Share in basis points; the shares of a split add up to 10000
+    pub bps: u16,
+}
+
+impl MintSettings {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.end_time == 0 || self.end_time > self.start_time,
+            crate::errors::UniversalNftError::InvalidInstructionData
+        );
+        if !self.proceeds.is_empty() {
+            require!(
+                self.proceeds.len() <= MAX_PROCEEDS_SHARES
+                    && self.proceeds.iter().all(|share| share.bps > 0 && share.recipient != Pubkey::default())
+                    && self.proceeds.iter().map(|share| share.bps as u32).sum::<u32>() == 10_000,
+                crate::errors::UniversalNftError::InvalidProceedsSplit
+            );
+        }
+        require!(
+            self.payment_mint.is_none() || self.price == 0 || !self.proceeds.is_empty(),
+            crate::errors::UniversalNftError::ProceedsSplitRequired
+        );
+        Ok(())
+    }
+    
+    /// Amount owed to each payee, the last one receiving the rounding remainder
+    pub fn proceeds_amounts(&self) -> Vec<u64> {
+        let mut amounts: Vec<u64> = self
+            .proceeds
+            .iter()
+            .map(|share| (self.price as u128 * share.bps as u128 / 10_000) as u64)
+            .collect();
+        if let Some((last, others)) = amounts.split_last_mut() {
+            *last = self.price - others.iter().sum::<u64>();
+        }
+        amounts
+    }
+}
+
+/// Minting terms and progress, see `mint_nft`
+#[account]
+#[derive(InitSpace)]
+pub struct MintConfig {
+    pub settings: MintSettings,
+    /// NFTs minted through `mint_nft` and `mint_batch` so far
+    pub minted: u64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl MintConfig {
+    /// Checks that `wallet_minted` more NFTs may be minted at `now`
+    pub fn check_mint(&self, now: i64, wallet_minted: u32) -> Result<()> {
+        let settings = &self.settings;
+        require!(
+            settings.start_time == 0 || now >= settings.start_time,
+            crate::errors::UniversalNftError::MintNotStarted
+        );
+        require!(
+            settings.end_time == 0 || now < settings.end_time,
+            crate::errors::UniversalNftError::MintEnded
+        );
+        require!(
+            settings.max_supply == 0 || self.minted < settings.max_supply,
+            crate::errors::UniversalNftError::MaxSupplyReached
+        );
+        require!(
+            settings.wallet_limit == 0 || wallet_minted < settings.wallet_limit,
+            crate::errors::UniversalNftError::WalletMintLimitReached
+        );
+        Ok(())
+    }
+
+    /// Checks that `count` more NFTs fit under the supply cap
+    pub fn check_supply(&self, count: u64) -> Result<()> {
+        let settings = &self.settings;
+        let minted = self
+            .minted
+            .checked_add(count)
+            .ok_or(crate::errors::UniversalNftError::ArithmeticOverflow)?;
+        require!(
+            settings.max_supply == 0 || minted <= settings.max_supply,
+            crate::errors::UniversalNftError::MaxSupplyReached
+        );
+        Ok(())
+    }
+}
+
+/// Claim tickets covered by one `AllowlistClaims` page
+pub const ALLOWLIST_TICKETS_PER_PAGE: u32 = 4096;
+
+/// Proof that a claimant may redeem `ticket` from the allowlist
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AllowlistProof {
+    /// First ticket of the claimant's entry
+    pub first_ticket: u32,
+    /// Number of tickets in the claimant's entry
+    pub allowance: u32,
+    /// Ticket redeemed by this claim, within the entry
+    pub ticket: u32,
+    /// Sibling hashes from the leaf up to the root
+    pub proof: Vec<[u8; 32]>,
+}
+
+impl AllowlistProof {
+    /// `AllowlistClaims` page holding the redeemed ticket
+    pub fn page(&self) -> u32 {
+        self.ticket / ALLOWLIST_TICKETS_PER_PAGE
+    }
+}
+
+/// Bitmap of redeemed allowlist tickets, one page per
+/// `ALLOWLIST_TICKETS_PER_PAGE` tickets of one tree
+#[account]
+#[derive(InitSpace)]
+pub struct AllowlistClaims {
+    /// Root of the tree the tickets belong to
+    pub merkle_root: [u8; 32],
+    pub page: u32,
+    pub claimed: [u8; 512],
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl AllowlistClaims {
+    /// Verifies `proof` for `claimant` against `merkle_root` and marks its
+    /// ticket as redeemed
+    pub fn redeem(
+        &mut self,
+        merkle_root: &[u8; 32],
+        claimant: &[u8; 32],
+        proof: &AllowlistProof,
+    ) -> Result<()> {
+        require!(
+            self.merkle_root == *merkle_root && self.page == proof.page(),
+            crate::errors::UniversalNftError::AllowlistPageMismatch
+        );
+        let in_entry = proof.ticket >= proof.first_ticket
+            && (proof.ticket - proof.first_ticket) < proof.allowance;
+        let leaf = crate::merkle::allowlist_leaf(claimant, proof.first_ticket, proof.allowance);
+        require!(
+            in_entry && crate::merkle::verify_proof(&proof.proof, merkle_root, leaf),
+            crate::errors::UniversalNftError::InvalidAllowlistProof
+        );
+        
+        let bit = (proof.ticket % ALLOWLIST_TICKETS_PER_PAGE) as usize;
+        let mask = 1u8 << (bit % 8);
+        require!(
+            self.claimed[bit / 8] & mask == 0,
+            crate::errors::UniversalNftError::TicketAlreadyClaimed
+        );
+        self.claimed[bit / 8] |= mask;
+        
+        Ok(())
+    }
+}
+
+/// NFTs a payer has minted through `mint_nft`, or an EVM claimant through
+/// allowlist claims
+#[account]
+#[derive(InitSpace)]
+pub struct MintRecord {
+    pub wallet: Pubkey,
+    pub minted: u32,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+/// Program treasury holding protocol fees and inbound SOL without a recipient
+#[account]
+#[derive(InitSpace)]
+pub struct Treasury {
+    /// Total lamports credited to the treasury
+    pub total_collected: u64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Treasury {
+    /// Adds `amount` lamports credited to the treasury to its running total
+    pub fn record_collected(&mut self, amount: u64) -> Result<()> {
+        self.total_collected = self
+            .total_collected
+            .checked_add(amount)
+            .ok_or(crate::errors::UniversalNftError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+/// Per-chain settings for cross-chain messaging
+#[account]
+#[derive(InitSpace)]
+pub struct ChainConfig {
+    /// Chain ID these settings apply to
+    pub chain_id: u64,
+    /// Codec used for messages exchanged with this chain
+    pub codec: MessageCodec,
+    /// Whether transfers to this chain are enabled; for ZetaChain, also
+    /// whether inbound messages are accepted
+    pub enabled: bool,
+    /// Lowest on_revert gas limit accepted for transfers to this chain
+    pub min_revert_gas_limit: u64,
+    /// Highest on_revert gas limit accepted for transfers to this chain
+    pub max_revert_gas_limit: u64,
+    /// Lamports deposited with the gateway to pay destination chain gas
+    pub gas_deposit: u64,
+    /// Flat protocol fee charged per transfer to this chain
+    pub fee_lamports: u64,
+    /// Protocol fee in basis points of the gas deposit
+    pub fee_bps: u16,
+    /// Universal NFT contract on this chain; for ZetaChain, the only caller
+    /// trusted to name the claimant of an allowlist claim. Zero if unset.
+    pub universal_contract: [u8; 20],
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+/// Largest protocol fee in basis points, the whole gas deposit
+pub const MAX_FEE_BPS: u16 = 10_000;
+
+impl ChainConfig {
+    /// Protocol fee charged for a transfer to this chain
+    pub fn transfer_fee(&self) -> Result<u64> {
+        let proportional = (self.gas_deposit as u128 * self.fee_bps as u128 / 10_000) as u64;
+        self.fee_lamports
+            .checked_add(proportional)
+            .ok_or_else(|| crate::errors::UniversalNftError::ArithmeticOverflow.into())
+    }
+    
+    /// Checks that an allowlist claim naming its claimant was relayed by this
+    /// chain's universal contract
+    pub fn check_claim_sender(&self, sender: &[u8; 20]) -> Result<()> {
+        require!(
+            self.universal_contract != [0u8; 20] && *sender == self.universal_contract,
+            crate::errors::UniversalNftError::UntrustedClaimSender
+        );
+        Ok(())
+    }
+    
+    /// Checks caller-supplied revert options against this chain's bounds
+    pub fn check_revert_options(&self, options: &TransferRevertOptions) -> Result<()> {
+        require!(
+            options.revert_address != Pubkey::default(),
+            crate::errors::UniversalNftError::InvalidRevertAddress
+        );
+        require!(
+            options.abort_address != [0u8; 20],
+            crate::errors::UniversalNftError::InvalidEvmAddress
+        );
+        if options.call_on_revert {
+            require!(
+                options.on_revert_gas_limit >= self.min_revert_gas_limit
+                    && options.on_revert_gas_limit <= self.max_revert_gas_limit,
+                crate::errors::UniversalNftError::RevertGasLimitOutOfBounds
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Wire format of cross-chain message payloads
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum MessageCodec {
+    /// Borsh `CrossChainMessage` in a versioned envelope
+    Borsh,
+    /// Solidity ABI encoding of the Universal NFT payload
+    Abi,
+}
+
+/// Link between a Solana wallet and an EVM address its owner controls
+#[account]
+#[derive(InitSpace)]
+pub struct AddressLink {
+    /// Solana wallet that registered the link
+    pub wallet: Pubkey,
+    /// EVM address proven with a secp256k1 signature
+    pub evm_address: [u8; 20],
+    /// Timestamp of the latest link
+    pub linked_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+/// EVM sender of a transfer from a wallet with `address_link`. Replies and
+/// refunds on the EVM side go to the sender, so it must be an address the
+/// wallet proved control of rather than one derived from its public key.
+pub fn linked_evm_sender(address_link: Option<&AddressLink>) -> Result<[u8; 20]> {
+    address_link
+        .map(|address_link| address_link.evm_address)
+        .ok_or_else(|| crate::errors::UniversalNftError::EvmAddressLinkRequired.into())
 }
 
 /// NFT origin information for cross-chain tracking
 #[account]
+#[derive(InitSpace)]
 pub struct NftOrigin {
     /// Original mint key (for NFTs first minted on Solana)
     pub original_mint: Pubkey,
@@ -49,11 +643,9 @@ pub struct NftOrigin {
     pub bump: u8,
 }
 
-impl NftOrigin {
-    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 1;
-}
-
 /// Cross-chain message data for ZetaChain integration
+///
+/// Sent inside a versioned envelope, see `crate::message`.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct CrossChainMessage {
     /// Message type (mint, transfer, burn)
@@ -72,6 +664,20 @@ pub struct CrossChainMessage {
     pub metadata: NftMetadata,
     /// Timestamp
     pub timestamp: i64,
+    /// Chain ID where the NFT was originally minted
+    pub origin_chain_id: u64,
+}
+
+/// Allowlist claim sent from another chain. `on_call` redeems it for
+/// `claimant` when relayed by the ZetaChain universal contract and mints a
+/// new NFT to `recipient`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AllowlistClaim {
+    /// EVM address whose allowlist entry is redeemed
+    pub claimant: [u8; 20],
+    pub recipient: [u8; 32],
+    pub metadata: NftMetadata,
+    pub proof: AllowlistProof,
 }
 
 /// Cross-chain message types
@@ -126,6 +732,7 @@ pub struct NftCollection {
 
 /// Cross-chain transfer state for tracking pending operations
 #[account]
+#[derive(InitSpace)]
 pub struct CrossChainTransfer {
     /// Token ID being transferred
     pub token_id: [u8; 32],
@@ -137,6 +744,14 @@ pub struct CrossChainTransfer {
     pub sender: Pubkey,
     /// Recipient address
     pub recipient: [u8; 32],
+    /// Solana address refunded if the transfer reverts
+    pub revert_address: Pubkey,
+    /// EVM address receiving funds if the transfer aborts
+    pub abort_address: [u8; 20],
+    /// Whether the gateway calls on_revert if the transfer reverts
+    pub call_on_revert: bool,
+    /// Gas limit for the on_revert call
+    pub on_revert_gas_limit: u64,
     /// Transfer status
     pub status: TransferStatus,
     /// Timestamp when transfer was initiated
@@ -144,17 +759,17 @@ pub struct CrossChainTransfer {
     /// Timestamp when transfer was completed (if applicable)
     pub completed_at: Option<i64>,
     /// Error message (if failed)
+    #[max_len(100)]
     pub error_message: Option<String>,
+    /// Keccak256 hash of the Borsh encoded metadata sent with the transfer,
+    /// checked against the `RevertContext` of a revert
+    pub metadata_hash: [u8; 32],
     /// Bump seed for PDA
     pub bump: u8,
 }
 
-impl CrossChainTransfer {
-    pub const LEN: usize = 8 + 32 + 8 + 8 + 32 + 32 + 1 + 8 + 9 + 4 + 100 + 1; // Approximate size
-}
-
 /// Transfer status enumeration
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
 pub enum TransferStatus {
     /// Transfer initiated but not yet processed by gateway
     Pending,
@@ -166,6 +781,60 @@ pub enum TransferStatus {
     Failed,
     /// Transfer cancelled
     Cancelled,
+    /// Transfer reverted by the gateway and deposit refunded
+    Reverted,
+    /// Transfer aborted by the gateway after the revert could not execute
+    Aborted,
+}
+
+/// Context attached as the revert message of outbound transfers, returned
+/// to `on_revert` if the transfer fails
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct RevertContext {
+    /// Layout version, see `crate::message::REVERT_CONTEXT_VERSION`
+    pub version: u8,
+    /// Token ID being transferred
+    pub token_id: [u8; 32],
+    /// Owner the NFT is restored to
+    pub original_owner: Pubkey,
+    /// Mint the NFT is restored to
+    pub mint: Pubkey,
+    /// Transfer record of the outbound transfer
+    pub transfer_record: Pubkey,
+    /// Keccak256 hash of the Borsh encoded metadata sent with the transfer
+    pub metadata_hash: [u8; 32],
+}
+
+/// Gas limit for on_revert when the caller does not choose one
+pub const DEFAULT_ON_REVERT_GAS_LIMIT: u64 = 200_000;
+
+/// Revert handling chosen by the caller of `transfer_cross_chain`.
+///
+/// The revert message is not included: the program always writes it, since
+/// `on_revert` relies on it to find the transfer.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct TransferRevertOptions {
+    /// Solana address to receive tokens back if revert occurs
+    pub revert_address: Pubkey,
+    /// EVM address to receive tokens if abort occurs
+    pub abort_address: [u8; 20],
+    /// Whether to call on_revert function
+    pub call_on_revert: bool,
+    /// Gas limit for revert operation
+    pub on_revert_gas_limit: u64,
+}
+
+impl TransferRevertOptions {
+    /// Default revert handling: refund `owner` on revert and its linked EVM
+    /// address on abort
+    pub fn for_owner(owner: Pubkey, linked_evm_address: [u8; 20]) -> Self {
+        Self {
+            revert_address: owner,
+            abort_address: linked_evm_address,
+            call_on_revert: true,
+            on_revert_gas_limit: DEFAULT_ON_REVERT_GAS_LIMIT,
+        }
+    }
 }
 
 /// Revert options for cross-chain operations (compatible with ZetaChain)
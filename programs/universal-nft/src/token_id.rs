@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+use crate::errors::*;
+
+/// Derives the universal token ID of an NFT.
+///
+/// `keccak256(uint64 origin_chain_id || bytes32 program_id || uint64 sequence)`
+/// with both integers big-endian, matching
+/// `keccak256(abi.encodePacked(uint64, bytes32, uint64))` in Solidity. The
+/// origin chain and program ID keep IDs from different deployments apart; the
+/// sequence keeps IDs from the same deployment apart.
+pub fn derive_token_id(origin_chain_id: u64, program_id: &Pubkey, sequence: u64) -> [u8; 32] {
+    keccak::hashv(&[
+        &origin_chain_id.to_be_bytes(),
+        program_id.as_ref(),
+        &sequence.to_be_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Formats the token ID as a decimal `uint256`, the way EVM tooling shows it.
+///
+/// The token ID bytes are a big-endian 256-bit integer, which is already the
+/// EVM `uint256` ABI word, so the decimal form is the only conversion needed.
+pub fn to_decimal_string(token_id: &[u8; 32]) -> String {
+    let mut value = *token_id;
+    let mut digits = Vec::new();
+
+    while value.iter().any(|b| *b != 0) {
+        // Long division of the big-endian value by 10
+        let mut remainder = 0u16;
+        for byte in value.iter_mut() {
+            let current = (remainder << 8) | *byte as u16;
+            *byte = (current / 10) as u8;
+            remainder = current % 10;
+        }
+        digits.push(b'0' + remainder as u8);
+    }
+
+    if digits.is_empty() {
+        return "0".to_string();
+    }
+    digits.reverse();
+    String::from_utf8(digits).unwrap()
+}
+
+/// Parses a decimal `uint256` into a token ID
+pub fn from_decimal_string(decimal: &str) -> Result<[u8; 32]> {
+    require!(
+        !decimal.is_empty() && decimal.bytes().all(|b| b.is_ascii_digit()),
+        UniversalNftError::InvalidTokenId
+    );
+
+    let mut value = [0u8; 32];
+    for digit in decimal.bytes() {
+        // value = value * 10 + digit, from the least significant byte up
+        let mut carry = (digit - b'0') as u16;
+        for byte in value.iter_mut().rev() {
+            let current = *byte as u16 * 10 + carry;
+            *byte = current as u8;
+            carry = current >> 8;
+        }
+        require!(carry == 0, UniversalNftError::InvalidTokenId);
+    }
+
+    Ok(value)
+}
@@ -0,0 +1,145 @@
+use universal_nft::abi::*;
+use universal_nft::errors::UniversalNftError;
+
+fn sample_payload() -> UniversalNftPayload {
+    UniversalNftPayload {
+        receiver: address_word(&[0xaa; 20]),
+        token_id: [0x11; 32],
+        uri: "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_string(),
+        sender: [0xbb; 20],
+        destination_chain_id: 101,
+        origin_chain_id: 11155111,
+        name: "Bridged NFT".to_string(),
+        symbol: "BNFT".to_string(),
+    }
+}
+
+#[test]
+fn round_trips_payload() {
+    let payload = sample_payload();
+
+    let decoded = decode_universal_nft_payload(&encode_universal_nft_payload(&payload)).unwrap();
+
+    assert_eq!(decoded, payload);
+}
+
+#[test]
+fn encodes_standard_head_and_tail() {
+    let payload = sample_payload();
+    let data = encode_universal_nft_payload(&payload);
+
+    // Head, then each string as a length word and one or three data words
+    assert_eq!(data.len(), 8 * WORD_LEN + 3 * WORD_LEN + 96 + 2 * WORD_LEN);
+    assert_eq!(data[0..32], payload.receiver);
+    assert_eq!(data[32..64], payload.token_id);
+    assert_eq!(data[88..96], 0x100u64.to_be_bytes());
+    assert_eq!(data[96..128], address_word(&payload.sender));
+    assert_eq!(data[152..160], 101u64.to_be_bytes());
+    assert_eq!(data[184..192], 11155111u64.to_be_bytes());
+    assert_eq!(data[216..224], 0x180u64.to_be_bytes());
+    assert_eq!(data[248..256], 0x1c0u64.to_be_bytes());
+    assert_eq!(data[287], payload.uri.len() as u8);
+    assert_eq!(data[415], payload.name.len() as u8);
+    assert_eq!(data[479], payload.symbol.len() as u8);
+}
+
+#[test]
+fn rejects_non_canonical_uri_offset() {
+    let mut data = encode_universal_nft_payload(&sample_payload());
+    data[95] = 0xa0;
+
+    assert_eq!(
+        decode_universal_nft_payload(&data).unwrap_err(),
+        UniversalNftError::InvalidAbiEncoding.into()
+    );
+}
+
+#[test]
+fn rejects_dirty_address_padding() {
+    let mut data = encode_universal_nft_payload(&sample_payload());
+    data[96] = 1;
+
+    assert_eq!(
+        decode_universal_nft_payload(&data).unwrap_err(),
+        UniversalNftError::InvalidAbiEncoding.into()
+    );
+}
+
+#[test]
+fn rejects_truncated_uri() {
+    let data = encode_universal_nft_payload(&sample_payload());
+
+    assert_eq!(
+        decode_universal_nft_payload(&data[..data.len() - WORD_LEN]).unwrap_err(),
+        UniversalNftError::MessageTruncated.into()
+    );
+}
+
+#[test]
+fn rejects_trailing_words() {
+    let mut data = encode_universal_nft_payload(&sample_payload());
+    data.extend_from_slice(&[0u8; WORD_LEN]);
+
+    assert_eq!(
+        decode_universal_nft_payload(&data).unwrap_err(),
+        UniversalNftError::InvalidAbiEncoding.into()
+    );
+}
+
+#[test]
+fn rejects_zero_token_id() {
+    let mut payload = sample_payload();
+    payload.token_id = [0u8; 32];
+
+    assert_eq!(
+        decode_universal_nft_payload(&encode_universal_nft_payload(&payload)).unwrap_err(),
+        UniversalNftError::ZeroTokenId.into()
+    );
+}
+
+#[test]
+fn rejects_non_canonical_string_order() {
+    let mut data = encode_universal_nft_payload(&sample_payload());
+    // Swap the name and symbol offsets
+    let name_offset = data[6 * WORD_LEN..7 * WORD_LEN].to_vec();
+    data.copy_within(7 * WORD_LEN..8 * WORD_LEN, 6 * WORD_LEN);
+    data[7 * WORD_LEN..8 * WORD_LEN].copy_from_slice(&name_offset);
+
+    assert_eq!(
+        decode_universal_nft_payload(&data).unwrap_err(),
+        UniversalNftError::InvalidAbiEncoding.into()
+    );
+}
+
+#[test]
+fn carries_destination_origin_and_metadata_into_message() {
+    let message = sample_payload().into_message(7001, 0);
+
+    assert_eq!(message.source_chain_id, 7001);
+    assert_eq!(message.destination_chain_id, 101);
+    assert_eq!(message.origin_chain_id, 11155111);
+    assert_eq!(message.metadata.name, "Bridged NFT");
+    assert_eq!(message.metadata.symbol, "BNFT");
+}
+
+#[test]
+fn rejects_payload_for_another_chain() {
+    let data = encode_universal_nft_payload(&sample_payload());
+
+    decode_inbound_payload(&data, 101).unwrap();
+    assert_eq!(
+        decode_inbound_payload(&data, 7001).unwrap_err(),
+        UniversalNftError::WrongDestinationChain.into()
+    );
+}
+
+#[test]
+fn rejects_zero_origin_chain() {
+    let mut payload = sample_payload();
+    payload.origin_chain_id = 0;
+
+    assert_eq!(
+        decode_universal_nft_payload(&encode_universal_nft_payload(&payload)).unwrap_err(),
+        UniversalNftError::InvalidChainId.into()
+    );
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use universal_nft::errors::UniversalNftError;
+use universal_nft::instructions::address_link_message;
+use universal_nft::secp256k1::*;
+
+mod common;
+use common::{evm_address, secp256k1_instruction};
+
+const EVM_KEY: [u8; 32] = [7u8; 32];
+
+#[test]
+fn accepts_signature_over_link_message() {
+    let wallet = Pubkey::new_unique();
+    let message = address_link_message(&wallet, &universal_nft::ID);
+
+    verify_secp256k1_instruction(&secp256k1_instruction(&EVM_KEY, &message, 0), 0, &evm_address(&EVM_KEY), &message)
+        .unwrap();
+}
+
+#[test]
+fn link_message_uses_personal_sign_prefix() {
+    let message = address_link_message(&Pubkey::new_unique(), &universal_nft::ID);
+
+    assert!(message.starts_with(b"\x19Ethereum Signed Message:\n"));
+}
+
+#[test]
+fn rejects_other_evm_address() {
+    let message = address_link_message(&Pubkey::new_unique(), &universal_nft::ID);
+
+    assert_eq!(
+        verify_secp256k1_instruction(&secp256k1_instruction(&EVM_KEY, &message, 0), 0, &[1u8; 20], &message)
+            .unwrap_err(),
+        UniversalNftError::SignatureMismatch.into()
+    );
+}
+
+#[test]
+fn rejects_signature_for_another_wallet() {
+    let signed = address_link_message(&Pubkey::new_unique(), &universal_nft::ID);
+    let expected = address_link_message(&Pubkey::new_unique(), &universal_nft::ID);
+
+    assert_eq!(
+        verify_secp256k1_instruction(&secp256k1_instruction(&EVM_KEY, &signed, 0), 0, &evm_address(&EVM_KEY), &expected)
+            .unwrap_err(),
+        UniversalNftError::SignatureMismatch.into()
+    );
+}
+
+#[test]
+fn rejects_offsets_into_another_instruction() {
+    let message = address_link_message(&Pubkey::new_unique(), &universal_nft::ID);
+
+    assert_eq!(
+        verify_secp256k1_instruction(&secp256k1_instruction(&EVM_KEY, &message, 1), 0, &evm_address(&EVM_KEY), &message)
+            .unwrap_err(),
+        UniversalNftError::InvalidSignatureInstruction.into()
+    );
+}
+
+#[test]
+fn rejects_instruction_of_another_program() {
+    let message = address_link_message(&Pubkey::new_unique(), &universal_nft::ID);
+    let mut instruction = secp256k1_instruction(&EVM_KEY, &message, 0);
+    instruction.program_id = Pubkey::new_unique();
+
+    assert_eq!(
+        verify_secp256k1_instruction(&instruction, 0, &evm_address(&EVM_KEY), &message).unwrap_err(),
+        UniversalNftError::InvalidSignatureInstruction.into()
+    );
+}
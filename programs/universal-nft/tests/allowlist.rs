@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+use universal_nft::errors::UniversalNftError;
+use universal_nft::merkle::*;
+use universal_nft::message::*;
+use universal_nft::state::*;
+
+mod common;
+use common::{sample_chain_config, sample_message};
+
+fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (low, high) = if a <= b { (a, b) } else { (b, a) };
+    keccak::hashv(&[low, high]).to_bytes()
+}
+
+/// Four-leaf allowlist tree with hand-built proofs
+struct Allowlist {
+    leaves: Vec<[u8; 32]>,
+    root: [u8; 32],
+}
+
+impl Allowlist {
+    fn new(entries: &[([u8; 32], u32, u32)]) -> Self {
+        let leaves: Vec<[u8; 32]> = entries
+            .iter()
+            .map(|(claimant, first_ticket, allowance)| allowlist_leaf(claimant, *first_ticket, *allowance))
+            .collect();
+        let root = hash_pair(&hash_pair(&leaves[0], &leaves[1]), &hash_pair(&leaves[2], &leaves[3]));
+        Allowlist { leaves, root }
+    }
+
+    fn proof(&self, index: usize, first_ticket: u32, allowance: u32, ticket: u32) -> AllowlistProof {
+        let pair = index ^ 1;
+        let other_half = if index < 2 { (2, 3) } else { (0, 1) };
+        AllowlistProof {
+            first_ticket,
+            allowance,
+            ticket,
+            proof: vec![
+                self.leaves[pair],
+                hash_pair(&self.leaves[other_half.0], &self.leaves[other_half.1]),
+            ],
+        }
+    }
+
+    fn claims(&self, page: u32) -> AllowlistClaims {
+        AllowlistClaims { merkle_root: self.root, page, claimed: [0u8; 512], bump: 255 }
+    }
+}
+
+/// A Solana wallet with tickets 0..3, an EVM address with ticket 3, and two
+/// other entries, the last one on the second claims page
+fn sample() -> (Allowlist, [u8; 32], [u8; 20]) {
+    let wallet = Pubkey::new_unique().to_bytes();
+    let evm_address = [0xabu8; 20];
+    let allowlist = Allowlist::new(&[
+        (wallet, 0, 3),
+        (evm_claimant(&evm_address), 3, 1),
+        (Pubkey::new_unique().to_bytes(), 4, 1),
+        (Pubkey::new_unique().to_bytes(), ALLOWLIST_TICKETS_PER_PAGE, 2),
+    ]);
+    (allowlist, wallet, evm_address)
+}
+
+#[test]
+fn redeems_each_ticket_of_an_entry_once() {
+    let (allowlist, wallet, _) = sample();
+    let mut claims = allowlist.claims(0);
+
+    for ticket in 0..3 {
+        claims.redeem(&allowlist.root, &wallet, &allowlist.proof(0, 0, 3, ticket)).unwrap();
+    }
+    assert_eq!(
+        claims.redeem(&allowlist.root, &wallet, &allowlist.proof(0, 0, 3, 1)).unwrap_err(),
+        UniversalNftError::TicketAlreadyClaimed.into()
+    );
+}
+
+#[test]
+fn rejects_ticket_outside_entry() {
+    let (allowlist, wallet, _) = sample();
+    let mut claims = allowlist.claims(0);
+
+    assert_eq!(
+        claims.redeem(&allowlist.root, &wallet, &allowlist.proof(0, 0, 3, 3)).unwrap_err(),
+        UniversalNftError::InvalidAllowlistProof.into()
+    );
+}
+
+#[test]
+fn rejects_other_claimant_and_inflated_allowance() {
+    let (allowlist, wallet, _) = sample();
+    let mut claims = allowlist.claims(0);
+
+    assert_eq!(
+        claims
+            .redeem(&allowlist.root, &Pubkey::new_unique().to_bytes(), &allowlist.proof(0, 0, 3, 0))
+            .unwrap_err(),
+        UniversalNftError::InvalidAllowlistProof.into()
+    );
+    assert_eq!(
+        claims.redeem(&allowlist.root, &wallet, &allowlist.proof(0, 0, 4, 0)).unwrap_err(),
+        UniversalNftError::InvalidAllowlistProof.into()
+    );
+}
+
+#[test]
+fn evm_claimant_uses_padded_address() {
+    let (allowlist, _, evm_address) = sample();
+    let mut claims = allowlist.claims(0);
+
+    claims
+        .redeem(&allowlist.root, &evm_claimant(&evm_address), &allowlist.proof(1, 3, 1, 3))
+        .unwrap();
+}
+
+#[test]
+fn tickets_map_to_their_page() {
+    let (allowlist, _, _) = sample();
+    let proof = allowlist.proof(3, ALLOWLIST_TICKETS_PER_PAGE, 2, ALLOWLIST_TICKETS_PER_PAGE + 1);
+    let claimant = Pubkey::new_unique().to_bytes();
+
+    assert_eq!(proof.page(), 1);
+    assert_eq!(
+        allowlist.claims(0).redeem(&allowlist.root, &claimant, &proof).unwrap_err(),
+        UniversalNftError::AllowlistPageMismatch.into()
+    );
+
+    let mut stale = allowlist.claims(1);
+    stale.merkle_root = [9u8; 32];
+    assert_eq!(
+        stale.redeem(&allowlist.root, &claimant, &proof).unwrap_err(),
+        UniversalNftError::AllowlistPageMismatch.into()
+    );
+}
+
+#[test]
+fn claim_envelope_round_trips() {
+    let (allowlist, _, _) = sample();
+    let claim = AllowlistClaim {
+        claimant: [9u8; 20],
+        recipient: [5u8; 32],
+        metadata: sample_message(CrossChainMessageType::Mint, [5u8; 32]).metadata,
+        proof: allowlist.proof(1, 3, 1, 3),
+    };
+
+    let data = encode_allowlist_claim(&claim).unwrap();
+    assert!(is_allowlist_claim(&data));
+    let decoded = decode_allowlist_claim(&data).unwrap();
+    assert_eq!(decoded.claimant, claim.claimant);
+    assert_eq!(decoded.proof, claim.proof);
+
+    let message = encode_cross_chain_message(&sample_message(CrossChainMessageType::Mint, [5u8; 32])).unwrap();
+    assert!(!is_allowlist_claim(&message));
+    assert_eq!(
+        decode_cross_chain_message(&data).unwrap_err(),
+        UniversalNftError::UnknownMessageType.into()
+    );
+}
+
+#[test]
+fn claims_are_only_trusted_from_the_universal_contract() {
+    let universal_contract = [4u8; 20];
+    let mut chain_config = ChainConfig { universal_contract, ..sample_chain_config() };
+
+    chain_config.check_claim_sender(&universal_contract).unwrap();
+    assert_eq!(
+        chain_config.check_claim_sender(&[5u8; 20]).unwrap_err(),
+        UniversalNftError::UntrustedClaimSender.into()
+    );
+
+    chain_config.universal_contract = [0u8; 20];
+    assert_eq!(
+        chain_config.check_claim_sender(&[0u8; 20]).unwrap_err(),
+        UniversalNftError::UntrustedClaimSender.into()
+    );
+}
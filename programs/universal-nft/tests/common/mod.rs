@@ -0,0 +1,112 @@
+#![allow(dead_code)]
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::{keccak, secp256k1_program};
+use universal_nft::secp256k1::{ETH_ADDRESS_LEN, SIGNATURE_OFFSETS_LEN};
+use universal_nft::state::*;
+
+/// A structurally valid message addressed to Solana
+pub fn sample_message(message_type: CrossChainMessageType, recipient: [u8; 32]) -> CrossChainMessage {
+    CrossChainMessage {
+        message_type,
+        token_id: [7u8; 32],
+        source_chain_id: 1,
+        destination_chain_id: SOLANA_CHAIN_ID,
+        sender: [1u8; 20],
+        recipient,
+        metadata: NftMetadata {
+            name: "Universal NFT".to_string(),
+            symbol: "UNFT".to_string(),
+            uri: "https://example.com/1.json".to_string(),
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+        },
+        timestamp: 0,
+        origin_chain_id: 1,
+    }
+}
+
+/// Settings of an enabled ZetaChain config with revert gas limit bounds and
+/// no fees
+pub fn sample_chain_config() -> ChainConfig {
+    ChainConfig {
+        chain_id: 7001,
+        codec: MessageCodec::Abi,
+        enabled: true,
+        min_revert_gas_limit: 100_000,
+        max_revert_gas_limit: 500_000,
+        gas_deposit: 0,
+        fee_lamports: 0,
+        fee_bps: 0,
+        universal_contract: [0u8; 20],
+        bump: 255,
+    }
+}
+
+/// Freshly initialized program state with nothing paused or queued
+pub fn sample_program_state() -> ProgramState {
+    ProgramState {
+        authority: Pubkey::new_unique(),
+        gateway: Pubkey::new_unique(),
+        next_token_id: 1,
+        total_minted: 0,
+        total_transfers: 0,
+        total_receives: 0,
+        version: PROGRAM_STATE_VERSION,
+        bump: 255,
+        tss_address: [0u8; 20],
+        tss_nonce: 0,
+        pauser: Pubkey::default(),
+        paused: 0,
+        pending_gateway: Pubkey::default(),
+        gateway_update_eta: 0,
+        gateway_update_delay: DEFAULT_GATEWAY_UPDATE_DELAY,
+        pending_authority: Pubkey::default(),
+        pending_gateway_update_delay: 0,
+        pending_tss_address: [0u8; 20],
+        tss_update_eta: 0,
+        pending_pauser: Pubkey::default(),
+        pauser_update_eta: 0,
+    }
+}
+
+/// Ethereum address of a secp256k1 secret key
+pub fn evm_address(secret_key: &[u8; 32]) -> [u8; 20] {
+    let secret_key = libsecp256k1::SecretKey::parse(secret_key).unwrap();
+    let public_key = libsecp256k1::PublicKey::from_secret_key(&secret_key).serialize();
+    keccak::hash(&public_key[1..]).to_bytes()[12..].try_into().unwrap()
+}
+
+/// Builds a secp256k1 program instruction in which `secret_key` signs
+/// `message` the way the SDK does, with all offsets pointing into the
+/// instruction at `instruction_index`
+pub fn secp256k1_instruction(secret_key: &[u8; 32], message: &[u8], instruction_index: u8) -> Instruction {
+    let digest = libsecp256k1::Message::parse(&keccak::hash(message).to_bytes());
+    let (signature, recovery_id) =
+        libsecp256k1::sign(&digest, &libsecp256k1::SecretKey::parse(secret_key).unwrap());
+
+    let eth_address_offset = 1 + SIGNATURE_OFFSETS_LEN;
+    let signature_offset = eth_address_offset + ETH_ADDRESS_LEN;
+    let message_offset = signature_offset + 64 + 1;
+
+    let mut data = vec![1u8];
+    data.extend_from_slice(&(signature_offset as u16).to_le_bytes());
+    data.push(instruction_index);
+    data.extend_from_slice(&(eth_address_offset as u16).to_le_bytes());
+    data.push(instruction_index);
+    data.extend_from_slice(&(message_offset as u16).to_le_bytes());
+    data.extend_from_slice(&(message.len() as u16).to_le_bytes());
+    data.push(instruction_index);
+    data.extend_from_slice(&evm_address(secret_key));
+    data.extend_from_slice(&signature.serialize());
+    data.push(recovery_id.serialize());
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: secp256k1_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
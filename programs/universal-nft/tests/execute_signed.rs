@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::keccak;
+use universal_nft::errors::UniversalNftError;
+use universal_nft::instructions::{execute_signed_message, verify_tss_execution};
+use universal_nft::state::*;
+
+mod common;
+use common::{evm_address, sample_program_state, secp256k1_instruction};
+
+const TSS_KEY: [u8; 32] = [3u8; 32];
+const NOW: i64 = 1_700_000_000;
+
+#[test]
+fn signed_message_layout() {
+    let data = b"payload".to_vec();
+    let message = execute_signed_message(SOLANA_CHAIN_ID, &universal_nft::ID, 5, &data);
+
+    assert_eq!(message.len(), 8 + 32 + 8 + 32);
+    assert_eq!(&message[..8], &SOLANA_CHAIN_ID.to_be_bytes());
+    assert_eq!(&message[8..40], universal_nft::ID.as_ref());
+    assert_eq!(&message[40..48], &5u64.to_be_bytes());
+    assert_eq!(&message[48..], &keccak::hash(&data).to_bytes());
+}
+
+#[test]
+fn signed_message_binds_nonce_and_deployment() {
+    let data = b"payload".to_vec();
+    let message = execute_signed_message(SOLANA_CHAIN_ID, &universal_nft::ID, 0, &data);
+
+    assert_ne!(message, execute_signed_message(SOLANA_CHAIN_ID, &universal_nft::ID, 1, &data));
+    assert_ne!(message, execute_signed_message(SOLANA_CHAIN_ID, &Pubkey::new_unique(), 0, &data));
+}
+
+fn tss_program_state() -> ProgramState {
+    ProgramState {
+        tss_address: evm_address(&TSS_KEY),
+        tss_nonce: 4,
+        ..sample_program_state()
+    }
+}
+
+/// Secp256k1 instruction in which `key` signs `data` at `nonce` for
+/// `chain_id` and `program_id`
+fn signed(key: &[u8; 32], chain_id: u64, program_id: &Pubkey, nonce: u64, data: &[u8]) -> Instruction {
+    secp256k1_instruction(key, &execute_signed_message(chain_id, program_id, nonce, data), 0)
+}
+
+#[test]
+fn accepts_tss_signature_with_next_nonce() {
+    let state = tss_program_state();
+    let instruction = signed(&TSS_KEY, SOLANA_CHAIN_ID, &universal_nft::ID, 4, b"payload");
+
+    verify_tss_execution(&state, &universal_nft::ID, &instruction, 0, 4, b"payload").unwrap();
+}
+
+#[test]
+fn rejects_signature_over_other_data() {
+    let state = tss_program_state();
+    let instruction = signed(&TSS_KEY, SOLANA_CHAIN_ID, &universal_nft::ID, 4, b"payload");
+
+    assert_eq!(
+        verify_tss_execution(&state, &universal_nft::ID, &instruction, 0, 4, b"tampered").unwrap_err(),
+        UniversalNftError::SignatureMismatch.into()
+    );
+}
+
+#[test]
+fn rejects_signature_by_other_key() {
+    let state = tss_program_state();
+    let instruction = signed(&[9u8; 32], SOLANA_CHAIN_ID, &universal_nft::ID, 4, b"payload");
+
+    assert_eq!(
+        verify_tss_execution(&state, &universal_nft::ID, &instruction, 0, 4, b"payload").unwrap_err(),
+        UniversalNftError::SignatureMismatch.into()
+    );
+}
+
+#[test]
+fn rejects_rotated_out_tss_key() {
+    let mut state = tss_program_state();
+    let eta = state.queue_tss_address(evm_address(&[5u8; 32]), NOW).unwrap().unwrap();
+    state.execute_queued_tss_update(eta).unwrap();
+    let instruction = signed(&TSS_KEY, SOLANA_CHAIN_ID, &universal_nft::ID, 4, b"payload");
+
+    assert_eq!(
+        verify_tss_execution(&state, &universal_nft::ID, &instruction, 0, 4, b"payload").unwrap_err(),
+        UniversalNftError::SignatureMismatch.into()
+    );
+}
+
+#[test]
+fn rejects_replayed_nonce() {
+    let state = tss_program_state();
+    let instruction = signed(&TSS_KEY, SOLANA_CHAIN_ID, &universal_nft::ID, 3, b"payload");
+
+    assert_eq!(
+        verify_tss_execution(&state, &universal_nft::ID, &instruction, 0, 3, b"payload").unwrap_err(),
+        UniversalNftError::InvalidNonce.into()
+    );
+}
+
+#[test]
+fn rejects_signature_for_other_chain() {
+    let state = tss_program_state();
+    let instruction = signed(&TSS_KEY, ZETACHAIN_CHAIN_ID, &universal_nft::ID, 4, b"payload");
+
+    assert_eq!(
+        verify_tss_execution(&state, &universal_nft::ID, &instruction, 0, 4, b"payload").unwrap_err(),
+        UniversalNftError::SignatureMismatch.into()
+    );
+}
+
+#[test]
+fn rejects_signature_for_other_program() {
+    let state = tss_program_state();
+    let instruction = signed(&TSS_KEY, SOLANA_CHAIN_ID, &Pubkey::new_unique(), 4, b"payload");
+
+    assert_eq!(
+        verify_tss_execution(&state, &universal_nft::ID, &instruction, 0, 4, b"payload").unwrap_err(),
+        UniversalNftError::SignatureMismatch.into()
+    );
+}
+
+#[test]
+fn rejects_execution_without_tss_address() {
+    let state = ProgramState { tss_address: [0u8; 20], ..tss_program_state() };
+    let instruction = signed(&TSS_KEY, SOLANA_CHAIN_ID, &universal_nft::ID, 4, b"payload");
+
+    assert_eq!(
+        verify_tss_execution(&state, &universal_nft::ID, &instruction, 0, 4, b"payload").unwrap_err(),
+        UniversalNftError::TssAddressNotSet.into()
+    );
+}
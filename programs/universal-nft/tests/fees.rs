@@ -0,0 +1,46 @@
+use universal_nft::errors::UniversalNftError;
+use universal_nft::state::*;
+
+mod common;
+use common::sample_chain_config;
+
+fn chain_config(gas_deposit: u64, fee_lamports: u64, fee_bps: u16) -> ChainConfig {
+    ChainConfig { gas_deposit, fee_lamports, fee_bps, ..sample_chain_config() }
+}
+
+#[test]
+fn unconfigured_chain_is_free() {
+    assert_eq!(chain_config(0, 0, 0).transfer_fee().unwrap(), 0);
+}
+
+#[test]
+fn combines_flat_fee_and_basis_points_of_gas_deposit() {
+    assert_eq!(chain_config(0, 5_000, 0).transfer_fee().unwrap(), 5_000);
+    assert_eq!(chain_config(2_000_000, 0, 250).transfer_fee().unwrap(), 50_000);
+    assert_eq!(chain_config(2_000_000, 5_000, 250).transfer_fee().unwrap(), 55_000);
+}
+
+#[test]
+fn basis_points_round_down() {
+    assert_eq!(chain_config(399, 0, 25).transfer_fee().unwrap(), 0);
+}
+
+#[test]
+fn rejects_fee_overflow() {
+    assert_eq!(
+        chain_config(u64::MAX, u64::MAX, MAX_FEE_BPS).transfer_fee().unwrap_err(),
+        UniversalNftError::ArithmeticOverflow.into()
+    );
+}
+
+#[test]
+fn rejects_treasury_total_overflow() {
+    let mut treasury = Treasury { total_collected: u64::MAX - 1, bump: 255 };
+
+    treasury.record_collected(1).unwrap();
+    assert_eq!(treasury.total_collected, u64::MAX);
+    assert_eq!(
+        treasury.record_collected(1).unwrap_err(),
+        UniversalNftError::ArithmeticOverflow.into()
+    );
+}
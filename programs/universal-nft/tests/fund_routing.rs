@@ -0,0 +1,43 @@
+use anchor_lang::prelude::Pubkey;
+use universal_nft::instructions::{fund_route_for, FundRoute};
+use universal_nft::state::*;
+
+mod common;
+use common::sample_message;
+
+#[test]
+fn mint_deposit_is_credited_to_recipient() {
+    let recipient = Pubkey::new_unique();
+    let msg = sample_message(CrossChainMessageType::Mint, recipient.to_bytes());
+
+    assert_eq!(fund_route_for(&msg), FundRoute::Recipient(recipient));
+}
+
+#[test]
+fn transfer_deposit_is_credited_to_recipient() {
+    let recipient = Pubkey::new_unique();
+    let msg = sample_message(CrossChainMessageType::Transfer, recipient.to_bytes());
+
+    assert_eq!(fund_route_for(&msg), FundRoute::Recipient(recipient));
+}
+
+#[test]
+fn zero_recipient_deposit_goes_to_treasury() {
+    let msg = sample_message(CrossChainMessageType::Transfer, [0u8; 32]);
+
+    assert_eq!(fund_route_for(&msg), FundRoute::Treasury);
+}
+
+#[test]
+fn revert_and_burn_deposits_go_to_treasury() {
+    let recipient = Pubkey::new_unique().to_bytes();
+
+    assert_eq!(
+        fund_route_for(&sample_message(CrossChainMessageType::Revert, recipient)),
+        FundRoute::Treasury
+    );
+    assert_eq!(
+        fund_route_for(&sample_message(CrossChainMessageType::Burn, recipient)),
+        FundRoute::Treasury
+    );
+}
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    self, construct_instructions_data, BorrowedInstruction,
+};
+use universal_nft::errors::UniversalNftError;
+use universal_nft::gateway::{gateway_pda_address, verify_gateway_caller};
+
+/// Accounts seen by a gateway-only instruction
+struct Caller {
+    gateway_pda: Pubkey,
+    pda_owner: Pubkey,
+    pda_signed: bool,
+    pda_lamports: u64,
+    pda_data: Vec<u8>,
+    sysvar_key: Pubkey,
+    sysvar_lamports: u64,
+    sysvar_data: Vec<u8>,
+}
+
+impl Caller {
+    /// The gateway invoking the program through CPI, signing with its PDA
+    fn gateway_cpi(gateway: &Pubkey) -> Self {
+        Self::top_level(gateway, *gateway, true)
+    }
+
+    fn top_level(top_level_program: &Pubkey, pda_owner: Pubkey, pda_signed: bool) -> Self {
+        let sysvar_data = construct_instructions_data(&[BorrowedInstruction {
+            program_id: top_level_program,
+            accounts: vec![],
+            data: &[],
+        }]);
+
+        Caller {
+            gateway_pda: Pubkey::new_unique(),
+            pda_owner,
+            pda_signed,
+            pda_lamports: 1,
+            pda_data: vec![],
+            sysvar_key: instructions::ID,
+            sysvar_lamports: 1,
+            sysvar_data,
+        }
+    }
+
+    fn verify(&mut self, gateway: &Pubkey) -> Result<()> {
+        let gateway_pda = AccountInfo::new(
+            &self.gateway_pda,
+            self.pda_signed,
+            true,
+            &mut self.pda_lamports,
+            &mut self.pda_data,
+            &self.pda_owner,
+            false,
+            0,
+        );
+        let sysvar_owner = anchor_lang::solana_program::sysvar::ID;
+        let instructions_sysvar = AccountInfo::new(
+            &self.sysvar_key,
+            false,
+            false,
+            &mut self.sysvar_lamports,
+            &mut self.sysvar_data,
+            &sysvar_owner,
+            false,
+            0,
+        );
+
+        verify_gateway_caller(gateway, &gateway_pda, &instructions_sysvar)
+    }
+}
+
+#[test]
+fn accepts_gateway_cpi() {
+    let gateway = Pubkey::new_unique();
+
+    Caller::gateway_cpi(&gateway).verify(&gateway).unwrap();
+}
+
+#[test]
+fn rejects_unsigned_gateway_pda() {
+    let gateway = Pubkey::new_unique();
+    let mut caller = Caller::top_level(&gateway, gateway, false);
+
+    assert_eq!(
+        caller.verify(&gateway).unwrap_err(),
+        UniversalNftError::InvalidGatewayCaller.into()
+    );
+}
+
+#[test]
+fn rejects_pda_of_another_program() {
+    let gateway = Pubkey::new_unique();
+    let mut caller = Caller::top_level(&gateway, Pubkey::new_unique(), true);
+
+    assert_eq!(
+        caller.verify(&gateway).unwrap_err(),
+        UniversalNftError::InvalidGatewayCaller.into()
+    );
+}
+
+#[test]
+fn rejects_call_not_made_through_gateway() {
+    let gateway = Pubkey::new_unique();
+    let mut caller = Caller::top_level(&universal_nft::ID, gateway, true);
+
+    assert_eq!(
+        caller.verify(&gateway).unwrap_err(),
+        UniversalNftError::InvalidGatewayCaller.into()
+    );
+}
+
+#[test]
+fn rejects_call_after_gateway_update() {
+    let old_gateway = Pubkey::new_unique();
+
+    assert_eq!(
+        Caller::gateway_cpi(&old_gateway)
+            .verify(&Pubkey::new_unique())
+            .unwrap_err(),
+        UniversalNftError::InvalidGatewayCaller.into()
+    );
+}
+
+#[test]
+fn rejects_forged_instructions_sysvar() {
+    let gateway = Pubkey::new_unique();
+    let mut caller = Caller::gateway_cpi(&gateway);
+    caller.sysvar_key = Pubkey::new_unique();
+
+    assert!(caller.verify(&gateway).is_err());
+}
+
+#[test]
+fn derives_published_gateway_pda() {
+    // Documented address of the ZetaChain gateway's meta PDA
+    assert_eq!(
+        gateway_pda_address(&universal_nft::ZETACHAIN_GATEWAY_ID),
+        anchor_lang::solana_program::pubkey!("2f9SLuUNb7TNeM6gzBwT4ZjbL5ZyKzzHg1Ce9yiquEjj")
+    );
+}
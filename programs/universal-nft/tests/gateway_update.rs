@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use universal_nft::errors::UniversalNftError;
+use universal_nft::state::*;
+
+mod common;
+use common::sample_program_state;
+
+const NOW: i64 = 1_700_000_000;
+
+#[test]
+fn queued_gateway_applies_after_delay() {
+    let mut state = sample_program_state();
+    let new_gateway = Pubkey::new_unique();
+
+    let eta = state.queue_gateway(new_gateway, NOW).unwrap();
+    assert_eq!(eta, NOW + DEFAULT_GATEWAY_UPDATE_DELAY);
+
+    state.execute_queued_update(eta).unwrap();
+    assert_eq!(state.gateway, new_gateway);
+    assert!(!state.has_queued_update());
+}
+
+#[test]
+fn rejects_early_execution() {
+    let mut state = sample_program_state();
+    let gateway = state.gateway;
+    let eta = state.queue_gateway(Pubkey::new_unique(), NOW).unwrap();
+
+    assert_eq!(
+        state.execute_queued_update(eta - 1).unwrap_err(),
+        UniversalNftError::GatewayUpdateNotReady.into()
+    );
+    assert_eq!(state.gateway, gateway);
+}
+
+#[test]
+fn cancelled_update_cannot_be_executed() {
+    let mut state = sample_program_state();
+    let gateway = state.gateway;
+    let eta = state.queue_gateway(Pubkey::new_unique(), NOW).unwrap();
+
+    state.cancel_queued_update().unwrap();
+    assert_eq!(
+        state.execute_queued_update(eta).unwrap_err(),
+        UniversalNftError::NoGatewayUpdateQueued.into()
+    );
+    assert_eq!(state.gateway, gateway);
+    assert_eq!(
+        state.cancel_queued_update().unwrap_err(),
+        UniversalNftError::NoGatewayUpdateQueued.into()
+    );
+}
+
+#[test]
+fn only_one_change_can_be_queued() {
+    let mut state = sample_program_state();
+    state.queue_gateway(Pubkey::new_unique(), NOW).unwrap();
+
+    assert_eq!(
+        state.queue_gateway(Pubkey::new_unique(), NOW).unwrap_err(),
+        UniversalNftError::GatewayUpdateAlreadyQueued.into()
+    );
+    assert_eq!(
+        state.change_gateway_update_delay(MIN_GATEWAY_UPDATE_DELAY, NOW).unwrap_err(),
+        UniversalNftError::GatewayUpdateAlreadyQueued.into()
+    );
+}
+
+#[test]
+fn delay_increase_applies_immediately() {
+    let mut state = sample_program_state();
+    let longer = DEFAULT_GATEWAY_UPDATE_DELAY * 2;
+
+    assert_eq!(state.change_gateway_update_delay(longer, NOW).unwrap(), None);
+    assert_eq!(state.gateway_update_delay, longer);
+    assert!(!state.has_queued_update());
+}
+
+#[test]
+fn delay_decrease_waits_for_current_delay() {
+    let mut state = sample_program_state();
+
+    let eta = state
+        .change_gateway_update_delay(MIN_GATEWAY_UPDATE_DELAY, NOW)
+        .unwrap()
+        .unwrap();
+    assert_eq!(eta, NOW + DEFAULT_GATEWAY_UPDATE_DELAY);
+    assert_eq!(state.gateway_update_delay, DEFAULT_GATEWAY_UPDATE_DELAY);
+
+    // A gateway change cannot slip in on the shorter delay meanwhile
+    assert_eq!(
+        state.queue_gateway(Pubkey::new_unique(), NOW).unwrap_err(),
+        UniversalNftError::GatewayUpdateAlreadyQueued.into()
+    );
+    assert_eq!(
+        state.execute_queued_update(eta - 1).unwrap_err(),
+        UniversalNftError::GatewayUpdateNotReady.into()
+    );
+
+    let gateway = state.gateway;
+    state.execute_queued_update(eta).unwrap();
+    assert_eq!(state.gateway_update_delay, MIN_GATEWAY_UPDATE_DELAY);
+    assert_eq!(state.gateway, gateway);
+}
+
+#[test]
+fn rejects_delay_below_minimum() {
+    assert_eq!(
+        sample_program_state()
+            .change_gateway_update_delay(MIN_GATEWAY_UPDATE_DELAY - 1, NOW)
+            .unwrap_err(),
+        UniversalNftError::GatewayUpdateDelayTooShort.into()
+    );
+}
+
+#[test]
+fn queued_tss_address_applies_after_delay() {
+    let mut state = sample_program_state();
+    let tss_address = [4u8; 20];
+
+    let eta = state.queue_tss_address(tss_address, NOW).unwrap().unwrap();
+    assert_eq!(eta, NOW + DEFAULT_GATEWAY_UPDATE_DELAY);
+    assert_eq!(
+        state.execute_queued_tss_update(eta - 1).unwrap_err(),
+        UniversalNftError::TssUpdateNotReady.into()
+    );
+    assert_eq!(state.tss_address, [0u8; 20]);
+
+    state.execute_queued_tss_update(eta).unwrap();
+    assert_eq!(state.tss_address, tss_address);
+    assert!(!state.has_queued_tss_update());
+}
+
+#[test]
+fn cancelled_tss_rotation_cannot_be_executed() {
+    let mut state = sample_program_state();
+    let eta = state.queue_tss_address([4u8; 20], NOW).unwrap().unwrap();
+
+    state.cancel_queued_tss_update().unwrap();
+    assert_eq!(
+        state.execute_queued_tss_update(eta).unwrap_err(),
+        UniversalNftError::NoTssUpdateQueued.into()
+    );
+    assert_eq!(state.tss_address, [0u8; 20]);
+}
+
+#[test]
+fn only_one_tss_rotation_can_be_queued() {
+    let mut state = sample_program_state();
+    state.queue_tss_address([4u8; 20], NOW).unwrap();
+
+    assert_eq!(
+        state.queue_tss_address([5u8; 20], NOW).unwrap_err(),
+        UniversalNftError::TssUpdateAlreadyQueued.into()
+    );
+}
+
+#[test]
+fn clearing_tss_address_applies_immediately() {
+    let mut state = ProgramState { tss_address: [4u8; 20], ..sample_program_state() };
+
+    assert_eq!(state.queue_tss_address([0u8; 20], NOW).unwrap(), None);
+    assert_eq!(state.tss_address, [0u8; 20]);
+    assert!(!state.has_queued_tss_update());
+}
@@ -0,0 +1,220 @@
+use anchor_lang::prelude::*;
+use universal_nft::errors::UniversalNftError;
+use universal_nft::message::*;
+use universal_nft::state::*;
+
+mod common;
+use common::sample_message;
+
+fn encoded(message: &CrossChainMessage) -> Vec<u8> {
+    encode_cross_chain_message(message).unwrap()
+}
+
+#[test]
+fn decodes_complete_message() {
+    let message = sample_message(CrossChainMessageType::Mint, [9u8; 32]);
+
+    let decoded = decode_inbound_message(&encoded(&message), SOLANA_CHAIN_ID).unwrap();
+
+    assert_eq!(decoded.message_type, CrossChainMessageType::Mint);
+    assert_eq!(decoded.token_id, message.token_id);
+    assert_eq!(decoded.recipient, message.recipient);
+    assert_eq!(decoded.origin_chain_id, message.origin_chain_id);
+}
+
+#[test]
+fn writes_current_version_header() {
+    let data = encoded(&sample_message(CrossChainMessageType::Transfer, [9u8; 32]));
+
+    assert_eq!(data[0..4], MESSAGE_MAGIC);
+    assert_eq!(data[4], CURRENT_MESSAGE_VERSION);
+    assert_eq!(data[5], CrossChainMessageType::Transfer as u8);
+    assert_eq!(
+        u32::from_le_bytes(data[6..10].try_into().unwrap()) as usize,
+        data.len() - ENVELOPE_HEADER_LEN
+    );
+}
+
+#[test]
+fn decodes_legacy_unenveloped_message() {
+    let message = sample_message(CrossChainMessageType::Transfer, [9u8; 32]);
+    let legacy = LegacyCrossChainMessage {
+        message_type: message.message_type.clone(),
+        token_id: message.token_id,
+        source_chain_id: 7001,
+        destination_chain_id: message.destination_chain_id,
+        sender: message.sender,
+        recipient: message.recipient,
+        metadata: message.metadata.clone(),
+        timestamp: message.timestamp,
+    };
+
+    let decoded = decode_inbound_message(&legacy.try_to_vec().unwrap(), SOLANA_CHAIN_ID).unwrap();
+
+    assert_eq!(decoded.token_id, message.token_id);
+    assert_eq!(decoded.source_chain_id, 7001);
+    assert_eq!(decoded.origin_chain_id, 7001);
+}
+
+#[test]
+fn rejects_empty_data() {
+    assert_eq!(
+        decode_cross_chain_message(&[]).unwrap_err(),
+        UniversalNftError::MessageTruncated.into()
+    );
+}
+
+#[test]
+fn rejects_bad_magic() {
+    let mut data = encoded(&sample_message(CrossChainMessageType::Mint, [9u8; 32]));
+    data[0] = b'X';
+
+    assert_eq!(
+        decode_cross_chain_message(&data).unwrap_err(),
+        UniversalNftError::InvalidMessageMagic.into()
+    );
+}
+
+#[test]
+fn rejects_unsupported_version() {
+    let mut data = encoded(&sample_message(CrossChainMessageType::Mint, [9u8; 32]));
+    data[4] = CURRENT_MESSAGE_VERSION + 1;
+
+    assert_eq!(
+        decode_cross_chain_message(&data).unwrap_err(),
+        UniversalNftError::UnsupportedMessageVersion.into()
+    );
+}
+
+#[test]
+fn rejects_truncated_message() {
+    let data = encoded(&sample_message(CrossChainMessageType::Mint, [9u8; 32]));
+
+    assert_eq!(
+        decode_cross_chain_message(&data[..data.len() - 3]).unwrap_err(),
+        UniversalNftError::MessageTruncated.into()
+    );
+}
+
+#[test]
+fn rejects_payload_shorter_than_its_layout() {
+    let data = encoded(&sample_message(CrossChainMessageType::Mint, [9u8; 32]));
+    let payload = &data[ENVELOPE_HEADER_LEN..data.len() - 3];
+
+    // The header matches the payload, so the payload itself is malformed
+    assert_eq!(
+        decode_cross_chain_message(&encode_envelope(CURRENT_MESSAGE_VERSION, 0, payload)).unwrap_err(),
+        UniversalNftError::InvalidCrossChainMessage.into()
+    );
+}
+
+#[test]
+fn rejects_trailing_bytes() {
+    let mut data = encoded(&sample_message(CrossChainMessageType::Mint, [9u8; 32]));
+    data.push(0);
+
+    assert_eq!(
+        decode_cross_chain_message(&data).unwrap_err(),
+        UniversalNftError::InvalidCrossChainMessage.into()
+    );
+}
+
+#[test]
+fn rejects_unknown_message_type() {
+    let mut data = encoded(&sample_message(CrossChainMessageType::Mint, [9u8; 32]));
+    data[5] = 4;
+
+    assert_eq!(
+        decode_cross_chain_message(&data).unwrap_err(),
+        UniversalNftError::UnknownMessageType.into()
+    );
+}
+
+#[test]
+fn rejects_header_type_mismatch() {
+    let mut data = encoded(&sample_message(CrossChainMessageType::Mint, [9u8; 32]));
+    data[5] = CrossChainMessageType::Transfer as u8;
+
+    assert_eq!(
+        decode_cross_chain_message(&data).unwrap_err(),
+        UniversalNftError::InvalidCrossChainMessage.into()
+    );
+}
+
+#[test]
+fn rejects_plain_text() {
+    assert!(decode_cross_chain_message(b"Revert Club #1").is_err());
+}
+
+#[test]
+fn rejects_zero_token_id() {
+    let mut message = sample_message(CrossChainMessageType::Mint, [9u8; 32]);
+    message.token_id = [0u8; 32];
+
+    assert_eq!(
+        decode_cross_chain_message(&encoded(&message)).unwrap_err(),
+        UniversalNftError::ZeroTokenId.into()
+    );
+}
+
+#[test]
+fn rejects_message_for_another_chain() {
+    let mut message = sample_message(CrossChainMessageType::Transfer, [9u8; 32]);
+    message.destination_chain_id = 7001;
+
+    assert_eq!(
+        decode_inbound_message(&encoded(&message), SOLANA_CHAIN_ID).unwrap_err(),
+        UniversalNftError::WrongDestinationChain.into()
+    );
+}
+
+fn sample_revert_context() -> RevertContext {
+    RevertContext {
+        version: REVERT_CONTEXT_VERSION,
+        token_id: [7u8; 32],
+        original_owner: Pubkey::new_unique(),
+        mint: Pubkey::new_unique(),
+        transfer_record: Pubkey::new_unique(),
+        metadata_hash: metadata_hash(&sample_message(CrossChainMessageType::Transfer, [9u8; 32]).metadata)
+            .unwrap(),
+    }
+}
+
+#[test]
+fn revert_context_round_trips() {
+    let context = sample_revert_context();
+
+    let data = encode_revert_context(&context).unwrap();
+    assert_eq!(data.len(), REVERT_CONTEXT_LEN);
+    let decoded = decode_revert_context(&data).unwrap();
+
+    assert_eq!(decoded, context);
+}
+
+#[test]
+fn revert_context_is_distinct_from_envelope() {
+    let data = encode_revert_context(&sample_revert_context()).unwrap();
+
+    assert_ne!(data[0..4], MESSAGE_MAGIC);
+}
+
+#[test]
+fn rejects_unknown_revert_context_version() {
+    let mut context = sample_revert_context();
+    context.version = REVERT_CONTEXT_VERSION + 1;
+
+    assert_eq!(
+        decode_revert_context(&encode_revert_context(&context).unwrap()).unwrap_err(),
+        UniversalNftError::UnsupportedMessageVersion.into()
+    );
+}
+
+#[test]
+fn rejects_truncated_revert_context() {
+    let data = encode_revert_context(&sample_revert_context()).unwrap();
+
+    assert_eq!(
+        decode_revert_context(&data[..data.len() - 1]).unwrap_err(),
+        UniversalNftError::MessageTruncated.into()
+    );
+}
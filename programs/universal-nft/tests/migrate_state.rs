@@ -0,0 +1,174 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use universal_nft::errors::UniversalNftError;
+use universal_nft::instructions::{
+    upgrade_chain_config, upgrade_program_state, ChainConfigV1, ChainConfigV2, ChainConfigV3,
+    ProgramStateV1,
+};
+use universal_nft::state::*;
+
+fn v1_account(version: u8) -> (ProgramStateV1, Vec<u8>) {
+    let v1 = ProgramStateV1 {
+        authority: Pubkey::new_unique(),
+        gateway: Pubkey::new_unique(),
+        next_token_id: 42,
+        total_minted: 41,
+        total_transfers: 7,
+        total_receives: 3,
+        version,
+        bump: 254,
+    };
+    let mut data = ProgramState::DISCRIMINATOR.to_vec();
+    v1.serialize(&mut data).unwrap();
+    (v1, data)
+}
+
+#[test]
+fn version_1_layout_matches_deployed_account() {
+    let (_, data) = v1_account(1);
+
+    assert_eq!(data.len(), 8 + 32 + 32 + 8 + 8 + 8 + 8 + 1 + 1);
+}
+
+#[test]
+fn upgrade_keeps_fields_and_fills_defaults() {
+    let (v1, data) = v1_account(1);
+    let state = upgrade_program_state(&data).unwrap();
+
+    assert_eq!(state.authority, v1.authority);
+    assert_eq!(state.gateway, v1.gateway);
+    assert_eq!(state.next_token_id, 42);
+    assert_eq!(state.total_minted, 41);
+    assert_eq!(state.total_transfers, 7);
+    assert_eq!(state.total_receives, 3);
+    assert_eq!(state.bump, 254);
+    assert_eq!(state.version, PROGRAM_STATE_VERSION);
+    assert_eq!(state.paused, 0);
+    assert_eq!(state.tss_address, [0u8; 20]);
+    assert_eq!(state.pending_authority, Pubkey::default());
+    assert_eq!(state.pending_gateway, Pubkey::default());
+    assert_eq!(state.pending_gateway_update_delay, 0);
+    assert_eq!(state.gateway_update_delay, DEFAULT_GATEWAY_UPDATE_DELAY);
+}
+
+#[test]
+fn upgraded_state_fits_allocated_space() {
+    let (_, data) = v1_account(1);
+    let state = upgrade_program_state(&data).unwrap();
+
+    let mut encoded = Vec::new();
+    state.try_serialize(&mut encoded).unwrap();
+    assert_eq!(encoded.len(), 8 + ProgramState::INIT_SPACE);
+
+    assert_eq!(
+        upgrade_program_state(&encoded).err().unwrap(),
+        UniversalNftError::StateAlreadyMigrated.into()
+    );
+}
+
+#[test]
+fn rejects_unknown_version_and_foreign_accounts() {
+    let (_, data) = v1_account(9);
+    assert_eq!(
+        upgrade_program_state(&data).err().unwrap(),
+        UniversalNftError::UnsupportedStateVersion.into()
+    );
+
+    let (_, mut data) = v1_account(1);
+    data[0] ^= 0xff;
+    assert_eq!(
+        upgrade_program_state(&data).err().unwrap(),
+        UniversalNftError::AccountValidationFailed.into()
+    );
+}
+
+fn chain_config_account(fields: impl AnchorSerialize) -> Vec<u8> {
+    let mut data = ChainConfig::DISCRIMINATOR.to_vec();
+    fields.serialize(&mut data).unwrap();
+    data
+}
+
+#[test]
+fn upgrades_chain_config_without_fees() {
+    let data = chain_config_account(ChainConfigV2 {
+        chain_id: 7001,
+        codec: MessageCodec::Abi,
+        enabled: true,
+        min_revert_gas_limit: 100_000,
+        max_revert_gas_limit: 500_000,
+        bump: 253,
+    });
+    let config = upgrade_chain_config(&data).unwrap();
+
+    assert_eq!(config.chain_id, 7001);
+    assert_eq!(config.codec, MessageCodec::Abi);
+    assert!(config.enabled);
+    assert_eq!(config.min_revert_gas_limit, 100_000);
+    assert_eq!(config.max_revert_gas_limit, 500_000);
+    assert_eq!(config.transfer_fee().unwrap(), 0);
+    assert_eq!(config.gas_deposit, 0);
+    assert_eq!(config.bump, 253);
+
+    let mut encoded = Vec::new();
+    config.try_serialize(&mut encoded).unwrap();
+    assert_eq!(encoded.len(), 8 + ChainConfig::INIT_SPACE);
+    assert_eq!(
+        upgrade_chain_config(&encoded).err().unwrap(),
+        UniversalNftError::ChainConfigAlreadyMigrated.into()
+    );
+}
+
+#[test]
+fn upgrades_chain_config_without_universal_contract() {
+    let data = chain_config_account(ChainConfigV3 {
+        chain_id: 7001,
+        codec: MessageCodec::Abi,
+        enabled: true,
+        min_revert_gas_limit: 100_000,
+        max_revert_gas_limit: 500_000,
+        gas_deposit: 2_000_000,
+        fee_lamports: 5_000,
+        fee_bps: 100,
+        bump: 253,
+    });
+    let config = upgrade_chain_config(&data).unwrap();
+
+    assert_eq!(config.gas_deposit, 2_000_000);
+    assert_eq!(config.fee_lamports, 5_000);
+    assert_eq!(config.fee_bps, 100);
+    assert_eq!(config.universal_contract, [0u8; 20]);
+    assert_eq!(config.bump, 253);
+}
+
+#[test]
+fn upgrades_chain_config_without_revert_gas_limits() {
+    let data = chain_config_account(ChainConfigV1 {
+        chain_id: 7001,
+        codec: MessageCodec::Borsh,
+        enabled: false,
+        bump: 253,
+    });
+    let config = upgrade_chain_config(&data).unwrap();
+
+    assert_eq!(config.chain_id, 7001);
+    assert!(!config.enabled);
+    assert_eq!(config.min_revert_gas_limit, DEFAULT_ON_REVERT_GAS_LIMIT);
+    assert_eq!(config.max_revert_gas_limit, DEFAULT_ON_REVERT_GAS_LIMIT);
+    assert_eq!(config.bump, 253);
+}
+
+#[test]
+fn rejects_unknown_chain_config_layout() {
+    let mut data = chain_config_account(ChainConfigV1 {
+        chain_id: 7001,
+        codec: MessageCodec::Borsh,
+        enabled: true,
+        bump: 253,
+    });
+    data.push(0);
+
+    assert_eq!(
+        upgrade_chain_config(&data).err().unwrap(),
+        UniversalNftError::AccountValidationFailed.into()
+    );
+}
@@ -0,0 +1,53 @@
+use universal_nft::errors::UniversalNftError;
+use universal_nft::instructions::{batch_account_groups, BATCH_MINT_ACCOUNTS_PER_ITEM};
+use universal_nft::state::*;
+
+fn mint_config(max_supply: u64, minted: u64) -> MintConfig {
+    MintConfig {
+        settings: MintSettings { max_supply, ..Default::default() },
+        minted,
+        bump: 255,
+    }
+}
+
+#[test]
+fn splits_remaining_accounts_into_groups_per_item() {
+    let accounts: Vec<usize> = (0..3 * BATCH_MINT_ACCOUNTS_PER_ITEM).collect();
+
+    let groups: Vec<&[usize]> = batch_account_groups(&accounts, 3).unwrap().collect();
+
+    assert_eq!(groups.len(), 3);
+    assert_eq!(groups[1], &[6, 7, 8, 9, 10, 11]);
+}
+
+#[test]
+fn rejects_empty_batches_and_mismatched_accounts() {
+    let accounts: Vec<usize> = (0..2 * BATCH_MINT_ACCOUNTS_PER_ITEM).collect();
+
+    assert_eq!(
+        batch_account_groups(&accounts[..0], 0).err().unwrap(),
+        UniversalNftError::InvalidBatch.into()
+    );
+    assert_eq!(
+        batch_account_groups(&accounts, 3).err().unwrap(),
+        UniversalNftError::InvalidBatch.into()
+    );
+    assert_eq!(
+        batch_account_groups(&accounts[1..], 2).err().unwrap(),
+        UniversalNftError::InvalidBatch.into()
+    );
+}
+
+#[test]
+fn batch_must_fit_under_supply_cap() {
+    mint_config(10, 7).check_supply(3).unwrap();
+    mint_config(0, u64::MAX - 1).check_supply(1).unwrap();
+    assert_eq!(
+        mint_config(10, 7).check_supply(4).unwrap_err(),
+        UniversalNftError::MaxSupplyReached.into()
+    );
+    assert_eq!(
+        mint_config(0, u64::MAX).check_supply(1).unwrap_err(),
+        UniversalNftError::ArithmeticOverflow.into()
+    );
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use universal_nft::errors::UniversalNftError;
+use universal_nft::state::*;
+
+fn mint_config(settings: MintSettings, minted: u64) -> MintConfig {
+    MintConfig { settings, minted, bump: 255 }
+}
+
+fn share(bps: u16) -> ProceedsShare {
+    ProceedsShare { recipient: Pubkey::new_unique(), bps }
+}
+
+#[test]
+fn default_settings_leave_minting_open() {
+    let config = mint_config(MintSettings::default(), 1_000_000);
+
+    MintSettings::default().validate().unwrap();
+    config.check_mint(0, u32::MAX - 1).unwrap();
+    assert!(MintSettings::default().proceeds_amounts().is_empty());
+}
+
+#[test]
+fn enforces_mint_window() {
+    let config = mint_config(
+        MintSettings { start_time: 100, end_time: 200, ..Default::default() },
+        0,
+    );
+
+    assert_eq!(config.check_mint(99, 0).unwrap_err(), UniversalNftError::MintNotStarted.into());
+    config.check_mint(100, 0).unwrap();
+    assert_eq!(config.check_mint(200, 0).unwrap_err(), UniversalNftError::MintEnded.into());
+}
+
+#[test]
+fn enforces_supply_cap_and_wallet_limit() {
+    let settings = MintSettings { max_supply: 10, wallet_limit: 2, ..Default::default() };
+
+    mint_config(settings.clone(), 9).check_mint(0, 1).unwrap();
+    assert_eq!(
+        mint_config(settings.clone(), 10).check_mint(0, 0).unwrap_err(),
+        UniversalNftError::MaxSupplyReached.into()
+    );
+    assert_eq!(
+        mint_config(settings, 0).check_mint(0, 2).unwrap_err(),
+        UniversalNftError::WalletMintLimitReached.into()
+    );
+}
+
+#[test]
+fn splits_proceeds_with_remainder_to_last_payee() {
+    let settings = MintSettings {
+        price: 1_000_001,
+        proceeds: vec![share(3_333), share(3_333), share(3_334)],
+        ..Default::default()
+    };
+
+    settings.validate().unwrap();
+    assert_eq!(settings.proceeds_amounts(), vec![333_300, 333_300, 333_401]);
+}
+
+#[test]
+fn rejects_invalid_settings() {
+    let invalid = [
+        MintSettings { proceeds: vec![share(5_000)], ..Default::default() },
+        MintSettings { proceeds: vec![share(10_000), share(0)], ..Default::default() },
+        MintSettings { proceeds: vec![share(2_000); 6], ..Default::default() },
+        MintSettings {
+            proceeds: vec![ProceedsShare { recipient: Pubkey::default(), bps: 10_000 }],
+            ..Default::default()
+        },
+    ];
+    for settings in invalid {
+        assert_eq!(
+            settings.validate().unwrap_err(),
+            UniversalNftError::InvalidProceedsSplit.into()
+        );
+    }
+
+    assert_eq!(
+        MintSettings { start_time: 200, end_time: 100, ..Default::default() }
+            .validate()
+            .unwrap_err(),
+        UniversalNftError::InvalidInstructionData.into()
+    );
+}
+
+#[test]
+fn requires_split_for_spl_priced_mints() {
+    let spl = MintSettings { price: 1_000, payment_mint: Some(Pubkey::new_unique()), ..Default::default() };
+
+    assert_eq!(spl.validate().unwrap_err(), UniversalNftError::ProceedsSplitRequired.into());
+    MintSettings { proceeds: vec![share(10_000)], ..spl.clone() }.validate().unwrap();
+    MintSettings { price: 0, ..spl }.validate().unwrap();
+}
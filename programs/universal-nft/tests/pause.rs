@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use universal_nft::errors::UniversalNftError;
+use universal_nft::state::*;
+
+mod common;
+use common::sample_program_state;
+
+fn program_state(paused: u8, pauser: Pubkey) -> ProgramState {
+    ProgramState { pauser, paused, ..sample_program_state() }
+}
+
+#[test]
+fn pause_bits_are_scoped() {
+    let state = program_state(PAUSE_OUTBOUND, Pubkey::default());
+
+    state.require_not_paused(PAUSE_MINT).unwrap();
+    state.require_not_paused(PAUSE_INBOUND).unwrap();
+    assert_eq!(
+        state.require_not_paused(PAUSE_OUTBOUND).unwrap_err(),
+        UniversalNftError::ProgramPaused.into()
+    );
+}
+
+#[test]
+fn nothing_is_paused_after_initialization() {
+    program_state(0, Pubkey::default()).require_not_paused(PAUSE_ALL).unwrap();
+}
+
+#[test]
+fn authority_and_pauser_can_pause() {
+    let pauser = Pubkey::new_unique();
+    let state = program_state(0, pauser);
+
+    assert!(state.can_pause(&state.authority));
+    assert!(state.can_pause(&pauser));
+    assert!(!state.can_pause(&Pubkey::new_unique()));
+}
+
+#[test]
+fn unset_pauser_grants_nothing() {
+    let state = program_state(0, Pubkey::default());
+
+    assert!(!state.can_pause(&Pubkey::default()));
+}
+
+#[test]
+fn pauser_change_waits_for_gateway_update_delay() {
+    let old_pauser = Pubkey::new_unique();
+    let new_pauser = Pubkey::new_unique();
+    let mut state = program_state(0, old_pauser);
+    let now = 1_700_000_000;
+
+    let eta = state.queue_pauser(new_pauser, now).unwrap();
+    assert_eq!(eta, now + DEFAULT_GATEWAY_UPDATE_DELAY);
+    assert_eq!(
+        state.execute_queued_pauser_update(eta - 1).unwrap_err(),
+        UniversalNftError::PauserUpdateNotReady.into()
+    );
+    // The current pauser can still veto queued updates in the meantime
+    assert!(state.can_pause(&old_pauser));
+
+    state.execute_queued_pauser_update(eta).unwrap();
+    assert!(state.can_pause(&new_pauser));
+    assert!(!state.can_pause(&old_pauser));
+    assert!(!state.has_queued_pauser_update());
+}
+
+#[test]
+fn pauser_removal_is_queued_too() {
+    let pauser = Pubkey::new_unique();
+    let mut state = program_state(0, pauser);
+
+    state.queue_pauser(Pubkey::default(), 0).unwrap();
+    assert!(state.has_queued_pauser_update());
+    assert_eq!(
+        state.queue_pauser(Pubkey::new_unique(), 0).unwrap_err(),
+        UniversalNftError::PauserUpdateAlreadyQueued.into()
+    );
+
+    state.cancel_queued_pauser_update().unwrap();
+    assert_eq!(state.pauser, pauser);
+    assert_eq!(
+        state.execute_queued_pauser_update(DEFAULT_GATEWAY_UPDATE_DELAY).unwrap_err(),
+        UniversalNftError::NoPauserUpdateQueued.into()
+    );
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use universal_nft::instructions::{transfer_quote, TransferQuote};
+use universal_nft::state::*;
+
+mod common;
+use common::sample_chain_config;
+
+fn chain_config(enabled: bool) -> ChainConfig {
+    ChainConfig {
+        enabled,
+        gas_deposit: 2_000_000,
+        fee_lamports: 5_000,
+        fee_bps: 250,
+        ..sample_chain_config()
+    }
+}
+
+#[test]
+fn quotes_fee_and_gas_deposit() {
+    let quote = transfer_quote(7001, Some(&chain_config(true)), 0).unwrap();
+
+    assert_eq!(
+        quote,
+        TransferQuote {
+            destination_chain_id: 7001,
+            enabled: true,
+            paused: false,
+            protocol_fee: 55_000,
+            gas_deposit: 2_000_000,
+            total: 2_055_000,
+        }
+    );
+}
+
+#[test]
+fn unconfigured_chain_is_disabled() {
+    let quote = transfer_quote(42, None, 0).unwrap();
+
+    assert!(!quote.enabled);
+    assert_eq!(quote.total, 0);
+}
+
+#[test]
+fn reports_outbound_pause_only() {
+    let config = chain_config(false);
+
+    assert!(transfer_quote(7001, Some(&config), PAUSE_OUTBOUND).unwrap().paused);
+    assert!(!transfer_quote(7001, Some(&config), PAUSE_MINT | PAUSE_INBOUND).unwrap().paused);
+    assert!(!transfer_quote(7001, Some(&config), 0).unwrap().enabled);
+}
+
+#[test]
+fn return_data_round_trips() {
+    let quote = transfer_quote(7001, Some(&chain_config(true)), 0).unwrap();
+    let data = quote.try_to_vec().unwrap();
+
+    assert_eq!(TransferQuote::try_from_slice(&data).unwrap(), quote);
+}
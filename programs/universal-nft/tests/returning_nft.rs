@@ -0,0 +1,124 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::token::spl_token::state::{Account, AccountState};
+use mpl_token_metadata::accounts::MasterEdition;
+use universal_nft::errors::UniversalNftError;
+use universal_nft::nft::*;
+use universal_nft::state::{NftOrigin, SOLANA_CHAIN_ID};
+
+fn origin(current_chain_id: u64) -> NftOrigin {
+    nft_origin(7001, current_chain_id)
+}
+
+fn native_origin(current_chain_id: u64) -> NftOrigin {
+    nft_origin(SOLANA_CHAIN_ID, current_chain_id)
+}
+
+fn nft_origin(origin_chain_id: u64, current_chain_id: u64) -> NftOrigin {
+    NftOrigin {
+        original_mint: Pubkey::new_unique(),
+        token_id: [7u8; 32],
+        origin_chain_id,
+        block_number: 1,
+        current_chain_id,
+        transfer_count: 1,
+        last_transfer_timestamp: 0,
+        bump: 255,
+    }
+}
+
+#[test]
+fn accepts_burned_nft_away_from_solana() {
+    assert!(check_nft_returning(0, &origin(7001)).is_ok());
+}
+
+#[test]
+fn rejects_nft_still_on_solana() {
+    for (supply, current_chain_id) in [(1, 7001), (0, SOLANA_CHAIN_ID), (1, SOLANA_CHAIN_ID)] {
+        assert_eq!(
+            check_nft_returning(supply, &origin(current_chain_id)).unwrap_err(),
+            UniversalNftError::NftAlreadyOnSolana.into(),
+            "supply {} on chain {}",
+            supply,
+            current_chain_id
+        );
+    }
+}
+
+#[test]
+fn native_nfts_are_frozen_instead_of_burned() {
+    assert_eq!(NftCustody::of(&native_origin(SOLANA_CHAIN_ID)), NftCustody::Freeze);
+    assert_eq!(NftCustody::of(&origin(SOLANA_CHAIN_ID)), NftCustody::Burn);
+}
+
+#[test]
+fn accepts_frozen_native_nft_away_from_solana() {
+    assert!(check_nft_returning(1, &native_origin(7001)).is_ok());
+
+    for (supply, current_chain_id) in [(0, 7001), (1, SOLANA_CHAIN_ID)] {
+        assert_eq!(
+            check_nft_returning(supply, &native_origin(current_chain_id)).unwrap_err(),
+            UniversalNftError::NftAlreadyOnSolana.into()
+        );
+    }
+}
+
+#[test]
+fn lock_instructions_are_signed_by_the_delegate_with_the_master_edition() {
+    let mint = Pubkey::new_unique();
+    let token_account = Pubkey::new_unique();
+    let mint_authority = Pubkey::new_unique();
+
+    for instruction in [
+        freeze_nft_instruction(&mint, &token_account, &mint_authority),
+        thaw_nft_instruction(&mint, &token_account, &mint_authority),
+    ] {
+        assert_eq!(instruction.program_id, mpl_token_metadata::ID);
+        let delegate = &instruction.accounts[0];
+        assert_eq!(delegate.pubkey, mint_authority);
+        assert!(delegate.is_signer && delegate.is_writable);
+        assert_eq!(instruction.accounts[1].pubkey, token_account);
+        assert_eq!(instruction.accounts[2].pubkey, MasterEdition::find_pda(&mint).0);
+        assert_eq!(instruction.accounts[3].pubkey, mint);
+    }
+}
+
+fn locked_account(mint: Pubkey, mint_authority: Pubkey) -> Account {
+    Account {
+        mint,
+        owner: Pubkey::new_unique(),
+        amount: 1,
+        delegate: Some(mint_authority).into(),
+        state: AccountState::Frozen,
+        delegated_amount: 1,
+        ..Account::default()
+    }
+}
+
+#[test]
+fn accepts_nft_frozen_for_the_mint_authority() {
+    let mint = Pubkey::new_unique();
+    let mint_authority = Pubkey::new_unique();
+
+    assert!(check_locked_nft(&locked_account(mint, mint_authority), &mint, &mint_authority).is_ok());
+}
+
+#[test]
+fn rejects_nft_not_locked_by_a_transfer() {
+    let mint = Pubkey::new_unique();
+    let mint_authority = Pubkey::new_unique();
+
+    let mut thawed = locked_account(mint, mint_authority);
+    thawed.state = AccountState::Initialized;
+    let mut other_delegate = locked_account(mint, mint_authority);
+    other_delegate.delegate = Some(Pubkey::new_unique()).into();
+    let mut empty = locked_account(mint, mint_authority);
+    empty.amount = 0;
+    let other_mint = locked_account(Pubkey::new_unique(), mint_authority);
+
+    for account in [thawed, other_delegate, empty, other_mint] {
+        assert_eq!(
+            check_locked_nft(&account, &mint, &mint_authority).unwrap_err(),
+            UniversalNftError::NftNotLocked.into()
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use universal_nft::errors::UniversalNftError;
+use universal_nft::state::*;
+
+mod common;
+use common::sample_chain_config;
+
+fn chain_config() -> ChainConfig {
+    ChainConfig { codec: MessageCodec::Borsh, ..sample_chain_config() }
+}
+
+fn options(on_revert_gas_limit: u64) -> TransferRevertOptions {
+    TransferRevertOptions {
+        revert_address: Pubkey::new_unique(),
+        abort_address: [3u8; 20],
+        call_on_revert: true,
+        on_revert_gas_limit,
+    }
+}
+
+#[test]
+fn accepts_gas_limit_within_bounds() {
+    let config = chain_config();
+
+    config.check_revert_options(&options(100_000)).unwrap();
+    config.check_revert_options(&options(DEFAULT_ON_REVERT_GAS_LIMIT)).unwrap();
+    config.check_revert_options(&options(500_000)).unwrap();
+}
+
+#[test]
+fn rejects_gas_limit_outside_bounds() {
+    let config = chain_config();
+
+    for gas_limit in [0, 99_999, 500_001] {
+        assert_eq!(
+            config.check_revert_options(&options(gas_limit)).unwrap_err(),
+            UniversalNftError::RevertGasLimitOutOfBounds.into(),
+            "{}",
+            gas_limit
+        );
+    }
+}
+
+#[test]
+fn ignores_gas_limit_without_on_revert_call() {
+    let mut options = options(0);
+    options.call_on_revert = false;
+
+    chain_config().check_revert_options(&options).unwrap();
+}
+
+#[test]
+fn rejects_missing_addresses() {
+    let mut no_revert_address = options(DEFAULT_ON_REVERT_GAS_LIMIT);
+    no_revert_address.revert_address = Pubkey::default();
+    assert_eq!(
+        chain_config().check_revert_options(&no_revert_address).unwrap_err(),
+        UniversalNftError::InvalidRevertAddress.into()
+    );
+
+    let mut no_abort_address = options(DEFAULT_ON_REVERT_GAS_LIMIT);
+    no_abort_address.abort_address = [0u8; 20];
+    assert_eq!(
+        chain_config().check_revert_options(&no_abort_address).unwrap_err(),
+        UniversalNftError::InvalidEvmAddress.into()
+    );
+}
+
+#[test]
+fn defaults_abort_to_linked_address() {
+    let owner = Pubkey::new_unique();
+    let options = TransferRevertOptions::for_owner(owner, [9u8; 20]);
+
+    assert_eq!(options.revert_address, owner);
+    assert_eq!(options.abort_address, [9u8; 20]);
+    chain_config().check_revert_options(&options).unwrap();
+}
+
+#[test]
+fn requires_linked_evm_sender() {
+    let address_link = AddressLink {
+        wallet: Pubkey::new_unique(),
+        evm_address: [9u8; 20],
+        linked_at: 0,
+        bump: 255,
+    };
+
+    assert_eq!(linked_evm_sender(Some(&address_link)).unwrap(), [9u8; 20]);
+    assert_eq!(
+        linked_evm_sender(None).unwrap_err(),
+        UniversalNftError::EvmAddressLinkRequired.into()
+    );
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use universal_nft::state::*;
+
+fn unassigned_roles() -> Roles {
+    Roles {
+        fee_manager: Pubkey::default(),
+        minter: Pubkey::default(),
+        chain_manager: Pubkey::default(),
+        bump: 255,
+    }
+}
+
+#[test]
+fn authority_holds_every_role() {
+    let authority = Pubkey::new_unique();
+    let roles = unassigned_roles();
+
+    for role in [Role::FeeManager, Role::Minter, Role::ChainManager] {
+        assert!(roles.authorizes(&authority, role, &authority));
+    }
+}
+
+#[test]
+fn holder_is_limited_to_its_role() {
+    let authority = Pubkey::new_unique();
+    let ops = Pubkey::new_unique();
+    let mut roles = unassigned_roles();
+    roles.set_holder(Role::FeeManager, ops);
+
+    assert_eq!(roles.holder(Role::FeeManager), ops);
+    assert!(roles.authorizes(&authority, Role::FeeManager, &ops));
+    assert!(!roles.authorizes(&authority, Role::ChainManager, &ops));
+    assert!(!roles.authorizes(&authority, Role::FeeManager, &Pubkey::new_unique()));
+}
+
+#[test]
+fn unassigned_role_grants_nothing() {
+    let roles = unassigned_roles();
+
+    assert!(!roles.authorizes(&Pubkey::new_unique(), Role::Minter, &Pubkey::default()));
+}
@@ -0,0 +1,55 @@
+use universal_nft::errors::UniversalNftError;
+use universal_nft::token_id::*;
+
+#[test]
+fn token_ids_differ_by_chain_program_and_sequence() {
+    let program_id = universal_nft::ID;
+    let base = derive_token_id(101, &program_id, 1);
+
+    assert_ne!(base, derive_token_id(101, &program_id, 2));
+    assert_ne!(base, derive_token_id(7000, &program_id, 1));
+    assert_ne!(base, derive_token_id(101, &anchor_lang::prelude::Pubkey::new_unique(), 1));
+}
+
+#[test]
+fn zero_displays_as_zero() {
+    assert_eq!(to_decimal_string(&[0u8; 32]), "0");
+    assert_eq!(from_decimal_string("0").unwrap(), [0u8; 32]);
+}
+
+#[test]
+fn rejects_non_decimal_input() {
+    for input in ["", "0x2a", "-1", "12 3"] {
+        assert_eq!(
+            from_decimal_string(input).unwrap_err(),
+            UniversalNftError::InvalidTokenId.into(),
+            "{:?}",
+            input
+        );
+    }
+}
+
+#[test]
+fn rejects_values_above_uint256() {
+    // 2^256
+    let overflow = "115792089237316195423570985008687907853269984665640564039457584007913129639936";
+
+    assert_eq!(
+        from_decimal_string(overflow).unwrap_err(),
+        UniversalNftError::InvalidTokenId.into()
+    );
+}
+
+#[test]
+fn mint_address_is_derived_from_token_id() {
+    let program_id = universal_nft::ID;
+    let token_id = derive_token_id(101, &program_id, 1);
+
+    let (mint, _) = universal_nft::nft::find_mint_address(&token_id, &program_id);
+
+    assert_eq!(
+        mint,
+        anchor_lang::prelude::Pubkey::find_program_address(&[b"mint", token_id.as_ref()], &program_id).0
+    );
+    assert_ne!(mint, universal_nft::nft::find_mint_address(&derive_token_id(101, &program_id, 2), &program_id).0);
+}
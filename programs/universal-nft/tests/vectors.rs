@@ -0,0 +1,336 @@
+//! Golden test vectors shared with the EVM counterpart contracts.
+//!
+//! Every fixture in `tests/vectors` pairs a message with its exact wire bytes.
+//! Each one is encoded and compared byte for byte, then decoded and compared
+//! field by field, so any change to the wire format shows up here.
+
+use anchor_lang::prelude::*;
+use serde::Deserialize;
+use universal_nft::abi::*;
+use universal_nft::gateway::*;
+use universal_nft::message::*;
+use universal_nft::state::*;
+use universal_nft::token_id::*;
+
+const BORSH_MESSAGES: &str = include_str!("vectors/borsh_messages.json");
+const ABI_PAYLOADS: &str = include_str!("vectors/abi_payloads.json");
+const DEPOSIT_AND_CALL: &str = include_str!("vectors/deposit_and_call.json");
+const TOKEN_IDS: &str = include_str!("vectors/token_ids.json");
+
+#[derive(Deserialize)]
+struct MessageVector {
+    name: String,
+    version: u8,
+    message: MessageFixture,
+    encoded: String,
+}
+
+#[derive(Deserialize)]
+struct MessageFixture {
+    message_type: String,
+    token_id: String,
+    source_chain_id: u64,
+    destination_chain_id: u64,
+    sender: String,
+    recipient: String,
+    metadata: MetadataFixture,
+    timestamp: i64,
+    origin_chain_id: u64,
+}
+
+#[derive(Deserialize)]
+struct MetadataFixture {
+    name: String,
+    symbol: String,
+    uri: String,
+    seller_fee_basis_points: u16,
+    creators: Option<Vec<CreatorFixture>>,
+    collection: Option<CollectionFixture>,
+}
+
+#[derive(Deserialize)]
+struct CreatorFixture {
+    address: String,
+    verified: bool,
+    share: u8,
+}
+
+#[derive(Deserialize)]
+struct CollectionFixture {
+    address: String,
+    verified: bool,
+}
+
+#[derive(Deserialize)]
+struct AbiVector {
+    name: String,
+    payload: AbiPayloadFixture,
+    encoded: String,
+}
+
+#[derive(Deserialize)]
+struct AbiPayloadFixture {
+    receiver: String,
+    token_id: String,
+    uri: String,
+    sender: String,
+    destination_chain_id: u64,
+    origin_chain_id: u64,
+    name: String,
+    symbol: String,
+}
+
+#[derive(Deserialize)]
+struct DepositAndCallVector {
+    name: String,
+    args: DepositAndCallFixture,
+    encoded: String,
+}
+
+#[derive(Deserialize)]
+struct DepositAndCallFixture {
+    amount: u64,
+    receiver: String,
+    message: String,
+    revert_options: Option<RevertOptionsFixture>,
+}
+
+#[derive(Deserialize)]
+struct RevertOptionsFixture {
+    revert_address: String,
+    abort_address: String,
+    call_on_revert: bool,
+    revert_message: String,
+    on_revert_gas_limit: u64,
+}
+
+#[derive(Deserialize)]
+struct TokenIdVector {
+    name: String,
+    origin_chain_id: Option<u64>,
+    program_id: Option<String>,
+    sequence: Option<u64>,
+    token_id: String,
+    decimal: String,
+}
+
+fn bytes(hex_str: &str) -> Vec<u8> {
+    hex::decode(hex_str).unwrap()
+}
+
+fn array<const N: usize>(hex_str: &str) -> [u8; N] {
+    bytes(hex_str).try_into().unwrap()
+}
+
+fn message_type(name: &str) -> CrossChainMessageType {
+    match name {
+        "Mint" => CrossChainMessageType::Mint,
+        "Transfer" => CrossChainMessageType::Transfer,
+        "Burn" => CrossChainMessageType::Burn,
+        "Revert" => CrossChainMessageType::Revert,
+        other => panic!("unknown message type {}", other),
+    }
+}
+
+impl MessageFixture {
+    fn to_message(&self) -> CrossChainMessage {
+        CrossChainMessage {
+            message_type: message_type(&self.message_type),
+            token_id: array(&self.token_id),
+            source_chain_id: self.source_chain_id,
+            destination_chain_id: self.destination_chain_id,
+            sender: array(&self.sender),
+            recipient: array(&self.recipient),
+            metadata: NftMetadata {
+                name: self.metadata.name.clone(),
+                symbol: self.metadata.symbol.clone(),
+                uri: self.metadata.uri.clone(),
+                seller_fee_basis_points: self.metadata.seller_fee_basis_points,
+                creators: self.metadata.creators.as_ref().map(|creators| {
+                    creators
+                        .iter()
+                        .map(|creator| NftCreator {
+                            address: array(&creator.address),
+                            verified: creator.verified,
+                            share: creator.share,
+                        })
+                        .collect()
+                }),
+                collection: self.metadata.collection.as_ref().map(|collection| NftCollection {
+                    address: Pubkey::new_from_array(array(&collection.address)),
+                    verified: collection.verified,
+                }),
+            },
+            timestamp: self.timestamp,
+            origin_chain_id: self.origin_chain_id,
+        }
+    }
+}
+
+fn encode_message_version(version: u8, message: &CrossChainMessage) -> Vec<u8> {
+    match version {
+        MESSAGE_VERSION_LEGACY => LegacyCrossChainMessage {
+            message_type: message.message_type.clone(),
+            token_id: message.token_id,
+            source_chain_id: message.source_chain_id,
+            destination_chain_id: message.destination_chain_id,
+            sender: message.sender,
+            recipient: message.recipient,
+            metadata: message.metadata.clone(),
+            timestamp: message.timestamp,
+        }
+        .try_to_vec()
+        .unwrap(),
+        CURRENT_MESSAGE_VERSION => encode_cross_chain_message(message).unwrap(),
+        other => panic!("no encoder for message version {}", other),
+    }
+}
+
+fn assert_messages_eq(name: &str, actual: &CrossChainMessage, expected: &CrossChainMessage) {
+    // CrossChainMessage does not implement PartialEq; its Borsh bytes are canonical
+    assert_eq!(
+        actual.try_to_vec().unwrap(),
+        expected.try_to_vec().unwrap(),
+        "{}: decoded message differs from fixture",
+        name
+    );
+}
+
+#[test]
+fn borsh_message_vectors_round_trip() {
+    let vectors: Vec<MessageVector> = serde_json::from_str(BORSH_MESSAGES).unwrap();
+    assert!(!vectors.is_empty());
+
+    for vector in &vectors {
+        let message = vector.message.to_message();
+        let expected = bytes(&vector.encoded);
+
+        assert_eq!(
+            hex::encode(encode_message_version(vector.version, &message)),
+            vector.encoded,
+            "{}: encoding differs from fixture",
+            vector.name
+        );
+
+        let decoded = decode_cross_chain_message(&expected)
+            .unwrap_or_else(|e| panic!("{}: failed to decode: {:?}", vector.name, e));
+        assert_messages_eq(&vector.name, &decoded, &message);
+    }
+}
+
+#[test]
+fn borsh_message_vectors_cover_every_message_type() {
+    let vectors: Vec<MessageVector> = serde_json::from_str(BORSH_MESSAGES).unwrap();
+
+    for name in ["Mint", "Transfer", "Burn", "Revert"] {
+        assert!(
+            vectors
+                .iter()
+                .any(|v| v.version == CURRENT_MESSAGE_VERSION && v.message.message_type == name),
+            "no current-version vector for {}",
+            name
+        );
+    }
+}
+
+#[test]
+fn abi_payload_vectors_round_trip() {
+    let vectors: Vec<AbiVector> = serde_json::from_str(ABI_PAYLOADS).unwrap();
+    assert!(!vectors.is_empty());
+
+    for vector in &vectors {
+        let payload = UniversalNftPayload {
+            receiver: array(&vector.payload.receiver),
+            token_id: array(&vector.payload.token_id),
+            uri: vector.payload.uri.clone(),
+            sender: array(&vector.payload.sender),
+            destination_chain_id: vector.payload.destination_chain_id,
+            origin_chain_id: vector.payload.origin_chain_id,
+            name: vector.payload.name.clone(),
+            symbol: vector.payload.symbol.clone(),
+        };
+
+        assert_eq!(
+            hex::encode(encode_universal_nft_payload(&payload)),
+            vector.encoded,
+            "{}: encoding differs from fixture",
+            vector.name
+        );
+
+        let decoded = decode_universal_nft_payload(&bytes(&vector.encoded))
+            .unwrap_or_else(|e| panic!("{}: failed to decode: {:?}", vector.name, e));
+        assert_eq!(decoded, payload, "{}: decoded payload differs from fixture", vector.name);
+    }
+}
+
+#[test]
+fn deposit_and_call_vectors_round_trip() {
+    let vectors: Vec<DepositAndCallVector> = serde_json::from_str(DEPOSIT_AND_CALL).unwrap();
+    assert!(!vectors.is_empty());
+
+    for vector in &vectors {
+        let args = DepositAndCallArgs {
+            amount: vector.args.amount,
+            receiver: array(&vector.args.receiver),
+            message: bytes(&vector.args.message),
+            revert_options: vector.args.revert_options.as_ref().map(|options| RevertOptions {
+                revert_address: Pubkey::new_from_array(array(&options.revert_address)),
+                abort_address: array(&options.abort_address),
+                call_on_revert: options.call_on_revert,
+                revert_message: bytes(&options.revert_message),
+                on_revert_gas_limit: options.on_revert_gas_limit,
+            }),
+        };
+
+        assert_eq!(
+            hex::encode(encode_deposit_and_call_data(&args).unwrap()),
+            vector.encoded,
+            "{}: encoding differs from fixture",
+            vector.name
+        );
+
+        let decoded = decode_deposit_and_call_data(&bytes(&vector.encoded))
+            .unwrap_or_else(|e| panic!("{}: failed to decode: {:?}", vector.name, e));
+        assert_eq!(
+            decoded.try_to_vec().unwrap(),
+            args.try_to_vec().unwrap(),
+            "{}: decoded arguments differ from fixture",
+            vector.name
+        );
+    }
+}
+
+#[test]
+fn token_id_vectors_round_trip() {
+    let vectors: Vec<TokenIdVector> = serde_json::from_str(TOKEN_IDS).unwrap();
+    assert!(!vectors.is_empty());
+
+    for vector in &vectors {
+        let token_id: [u8; 32] = array(&vector.token_id);
+
+        if let (Some(origin_chain_id), Some(program_id), Some(sequence)) =
+            (vector.origin_chain_id, &vector.program_id, vector.sequence)
+        {
+            let program_id = Pubkey::new_from_array(array(program_id));
+            assert_eq!(
+                derive_token_id(origin_chain_id, &program_id, sequence),
+                token_id,
+                "{}: derived token ID differs from fixture",
+                vector.name
+            );
+        }
+
+        assert_eq!(
+            to_decimal_string(&token_id),
+            vector.decimal,
+            "{}: decimal display differs from fixture",
+            vector.name
+        );
+        assert_eq!(
+            from_decimal_string(&vector.decimal).unwrap(),
+            token_id,
+            "{}: parsed decimal differs from fixture",
+            vector.name
+        );
+    }
+}
@@ -96,26 +96,37 @@ describe("Universal NFT Integration Tests", () => {
     }
   });
 
-  it("Updates gateway address", async () => {
+  it("Queues and cancels a gateway update", async () => {
     const newGateway = Keypair.generate();
 
     try {
       const tx = await program.methods
-        .updateGateway(newGateway.publicKey)
+        .queueGatewayUpdate(newGateway.publicKey)
         .accounts({
           programState: programStatePda,
           authority: authority.publicKey,
-          newGateway: newGateway.publicKey,
         })
         .rpc();
 
-      console.log("✅ Update gateway transaction:", tx);
+      console.log("✅ Queue gateway update transaction:", tx);
 
-      // Verify gateway was updated
-      const programState = await program.account.programState.fetch(programStatePda);
-      expect(programState.gateway.toString()).to.equal(newGateway.publicKey.toString());
+      // The gateway only changes once the update delay has elapsed
+      let programState = await program.account.programState.fetch(programStatePda);
+      expect(programState.pendingGateway.toString()).to.equal(newGateway.publicKey.toString());
+      expect(programState.gateway.toString()).to.not.equal(newGateway.publicKey.toString());
+
+      await program.methods
+        .cancelGatewayUpdate()
+        .accounts({
+          programState: programStatePda,
+          signer: authority.publicKey,
+        })
+        .rpc();
+
+      programState = await program.account.programState.fetch(programStatePda);
+      expect(programState.pendingGateway.toString()).to.equal(PublicKey.default.toString());
     } catch (error) {
-      console.error("❌ Update gateway failed:", error);
+      console.error("❌ Gateway update queueing failed:", error);
       throw error;
     }
   });